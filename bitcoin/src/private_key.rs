@@ -78,12 +78,12 @@ impl<N: BitcoinNetwork> FromStr for BitcoinPrivateKey<N> {
             return Err(PrivateKeyError::InvalidByteLength(len));
         }
 
-        let expected = &data[len - 4..len];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
+        let mut actual = [0u8; 4];
+        actual.copy_from_slice(&data[len - 4..len]);
+        let mut expected = [0u8; 4];
+        expected.copy_from_slice(&checksum(&data[0..len - 4])[0..4]);
+        if actual != expected {
+            return Err(PrivateKeyError::InvalidChecksum { expected, actual });
         }
 
         // Check that the wif prefix corresponds to the correct network.
@@ -74,6 +74,9 @@ pub enum ExtendedPrivateKeyError {
 
     #[fail(display = "unsupported format: {}", _0)]
     UnsupportedFormat(String),
+
+    #[fail(display = "wrong passphrase")]
+    WrongPassphrase,
 }
 
 impl From<DerivationPathError> for ExtendedPrivateKeyError {
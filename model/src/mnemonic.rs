@@ -82,6 +82,9 @@ pub enum MnemonicError {
     #[fail(display = "Invalid phrase: {}", _0)]
     InvalidPhrase(String),
 
+    #[fail(display = "Invalid shares: {}", _0)]
+    InvalidShares(String),
+
     #[fail(display = "Invalid word not found in monero: {}", _0)]
     InvalidWord(String),
 
@@ -35,8 +35,11 @@ pub enum PrivateKeyError {
     #[fail(display = "invalid character length: {}", _0)]
     InvalidCharacterLength(usize),
 
-    #[fail(display = "invalid private key checksum: {{ expected: {:?}, found: {:?} }}", _0, _1)]
-    InvalidChecksum(String, String),
+    #[fail(
+        display = "invalid private key checksum: {{ expected: {:02x?}, actual: {:02x?} }}",
+        expected, actual
+    )]
+    InvalidChecksum { expected: [u8; 4], actual: [u8; 4] },
 
     #[fail(display = "invalid network: {{ expected: {:?}, found: {:?} }}", _0, _1)]
     InvalidNetwork(String, String),
@@ -44,6 +47,15 @@ pub enum PrivateKeyError {
     #[fail(display = "invalid private key prefix: {:?}", _0)]
     InvalidPrefix(Vec<u8>),
 
+    #[fail(display = "the public key does not correspond to the secret key")]
+    KeypairMismatch,
+
+    #[fail(display = "legacy uncompressed private key rejected")]
+    LegacyUncompressedRejected,
+
+    #[fail(display = "invalid WIF: a raw base58 secret key has no version byte or checksum")]
+    LooksLikeRawKeyNotWif,
+
     #[fail(display = "{}", _0)]
     Message(String),
 
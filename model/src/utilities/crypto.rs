@@ -9,6 +9,21 @@ pub fn hash160(bytes: &[u8]) -> Vec<u8> {
     Ripemd160::digest(&Sha256::digest(&bytes)).to_vec()
 }
 
+/// Returns `true` if the given byte strings are equal. The running time depends only on their lengths,
+/// not on their contents, so secrets such as private keys can be compared without leaking
+/// the position of the first mismatch.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |difference, (x, y)| difference | (x ^ y)) == 0
+}
+
+/// Returns `true` if the given strings are equal, in the constant time of `ct_eq`.
+pub fn ct_eq_str(a: &str, b: &str) -> bool {
+    ct_eq(a.as_bytes(), b.as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,4 +57,23 @@ mod tests {
         ];
         test_hash160(b"00000000000000000000000000000000", &expected_bytes)
     }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(&[1, 2, 3], &[1, 2, 3]));
+        assert!(ct_eq(&[], &[]));
+        assert!(!ct_eq(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!ct_eq(&[1, 2, 3], &[1, 2]));
+    }
+
+    #[test]
+    fn test_ct_eq_str() {
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        assert!(ct_eq_str(wif, wif));
+        assert!(ct_eq_str("", ""));
+        assert!(!ct_eq_str(wif, "M3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g"));
+        assert!(!ct_eq_str(wif, "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4h"));
+        assert!(!ct_eq_str(wif, &wif[1..]));
+        assert!(!ct_eq_str(wif, ""));
+    }
 }
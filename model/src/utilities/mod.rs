@@ -1,6 +1,7 @@
 #[cfg_attr(test, macro_use)]
 pub mod bytes;
 pub mod crypto;
+pub mod normalize;
 
 pub fn to_hex_string(bytes: &[u8]) -> String {
     bytes
@@ -0,0 +1,40 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// Returns the compatibility decomposition (NFKD) of the given string, as BIP39 requires of
+/// mnemonic phrases and passwords, so that differently-encoded forms of the same text agree.
+pub fn nfkd(s: &str) -> String {
+    s.nfkd().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nfkd() {
+        assert_eq!("", nfkd(""));
+        assert_eq!("abandon about", nfkd("abandon about"));
+
+        // Precomposed and decomposed Latin letters
+        assert_eq!("cafe\u{301}", nfkd("caf\u{e9}"));
+        assert_eq!("cafe\u{301}", nfkd("cafe\u{301}"));
+        assert_eq!("n\u{303}", nfkd("\u{f1}"));
+
+        // Combining marks are reordered by combining class
+        assert_eq!("a\u{323}\u{302}", nfkd("a\u{302}\u{323}"));
+        assert_eq!(nfkd("\u{1ead}"), nfkd("\u{e2}\u{323}"));
+
+        // Compatibility characters
+        assert_eq!("ABC 1", nfkd("\u{ff21}\u{ff22}\u{ff23}\u{3000}\u{ff11}"));
+
+        // Japanese kana with voicing marks and Korean Hangul syllables
+        assert_eq!("\u{304b}\u{3099}", nfkd("\u{304c}"));
+        assert_eq!("\u{1100}\u{1161}", nfkd("\u{ac00}"));
+        assert_eq!("\u{1112}\u{1161}\u{11ab}", nfkd("\u{d55c}"));
+
+        // Greek and Cyrillic letters with diacritics
+        assert_eq!("\u{3b1}\u{301}", nfkd("\u{3ac}"));
+        assert_eq!("\u{3c9}\u{314}\u{342}\u{345}", nfkd("\u{1fa7}"));
+        assert_eq!("\u{438}\u{306}", nfkd("\u{439}"));
+    }
+}
@@ -4,5 +4,7 @@ pub use self::bip39::*;
 pub mod monero;
 pub use self::monero::*;
 
+pub mod slip39;
+
 pub mod wordlist;
 pub use self::wordlist::*;
@@ -0,0 +1,78 @@
+use std::process::{Command, Output};
+
+/// Seed of "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+const SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+/// Returns the output of the wagyu binary run with the given arguments.
+fn wagyu(arguments: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wagyu"))
+        .args(arguments)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn zcash_derive() {
+    let output = wagyu(&["zcash", "derive", "--seed", SEED, "--path", "m/44'/133'/0'/0/0"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F"));
+
+    let output = wagyu(&[
+        "zcash",
+        "derive",
+        "--json",
+        "--seed",
+        SEED,
+        "--path",
+        "m/44'/133'/0'/0/1",
+    ]);
+    assert!(output.status.success());
+    let wallets: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!("t1aQ2b1XszNVo15BguYLbQGqETBL9QZA8Jq", wallets[0]["address"]);
+    assert!(wallets[0]["private_key"].as_str().unwrap().starts_with(['K', 'L']));
+
+    // BIP32 test vector 1
+    let output = wagyu(&[
+        "zcash",
+        "derive",
+        "--json",
+        "--seed",
+        "000102030405060708090a0b0c0d0e0f",
+        "--path",
+        "m/0'",
+    ]);
+    assert!(output.status.success());
+    let wallets: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+        wallets[0]["extended_private_key"]
+    );
+}
+
+#[test]
+fn zcash_derive_invalid() {
+    // Invalid seed length
+    let output = wagyu(&["zcash", "derive", "--seed", "00010203", "--path", "m/0"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("invalid seed length: 4 bytes"));
+
+    // Missing seed or path
+    let output = wagyu(&["zcash", "derive", "-n", "testnet"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("both --seed and --path are required"));
+    let output = wagyu(&["zcash", "derive", "--seed", SEED]);
+    assert!(!output.status.success());
+    let output = wagyu(&["zcash", "derive", "--path", "m/0"]);
+    assert!(!output.status.success());
+
+    // Invalid path
+    let output = wagyu(&["zcash", "derive", "--seed", SEED, "--path", "44'/133'/0'/0/0"]);
+    assert!(!output.status.success());
+    let output = wagyu(&["zcash", "derive", "--seed", SEED, "--path", "m/44'/x/0"]);
+    assert!(!output.status.success());
+}
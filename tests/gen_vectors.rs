@@ -0,0 +1,30 @@
+use std::process::{Command, Output};
+
+/// Returns the output of the wagyu binary run with the given arguments.
+fn wagyu(arguments: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wagyu"))
+        .args(arguments)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn zcash_gen_vectors() {
+    let output = wagyu(&["zcash", "gen-vectors", "--count", "5", "--seed", "7"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let vectors: Vec<(String, String, String, bool, String)> = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(5, vectors.len());
+    vectors.iter().for_each(|(wif, secret_key, network, _, address)| {
+        assert_eq!(64, secret_key.len());
+        assert!(network == "mainnet" || network == "testnet");
+        assert!(!wif.is_empty());
+        assert!(address.starts_with('t'));
+    });
+
+    // The same seed generates the same test vectors.
+    let repeated = wagyu(&["zcash", "gen-vectors", "--count", "5", "--seed", "7"]);
+    assert_eq!(stdout, String::from_utf8(repeated.stdout).unwrap());
+
+    assert!(!wagyu(&["zcash", "gen-vectors", "--seed", "seven"]).status.success());
+}
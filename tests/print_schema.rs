@@ -0,0 +1,18 @@
+use std::process::Command;
+
+#[test]
+fn zcash_print_schema() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wagyu"))
+        .args(["zcash", "--print-schema"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!("array", schema["type"]);
+
+    let properties = schema["items"]["properties"].as_object().unwrap();
+    assert!(properties.contains_key("private_key"));
+    assert!(properties.contains_key("address"));
+    assert_eq!(serde_json::json!(["address"]), schema["items"]["required"]);
+}
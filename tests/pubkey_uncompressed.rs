@@ -0,0 +1,32 @@
+use std::process::Command;
+
+/// Returns the stdout of the wagyu binary run with the given arguments.
+fn wagyu(arguments: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_wagyu"))
+        .args(arguments)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Returns `true` if the output contains an uncompressed secp256k1 public key in hex.
+fn contains_uncompressed_public_key(output: &str) -> bool {
+    output
+        .split(|c: char| !c.is_ascii_hexdigit())
+        .any(|word| word.len() == 130 && word.starts_with("04"))
+}
+
+#[test]
+fn zcash_pubkey_uncompressed() {
+    let output = wagyu(&["zcash", "--pubkey-uncompressed"]);
+    assert!(output.contains("Uncompressed Key"));
+    assert!(contains_uncompressed_public_key(&output));
+
+    let output = wagyu(&["zcash", "--json", "--pubkey-uncompressed"]);
+    assert!(output.contains("\"public_key_uncompressed\""));
+    assert!(contains_uncompressed_public_key(&output));
+
+    let output = wagyu(&["zcash"]);
+    assert!(!contains_uncompressed_public_key(&output));
+}
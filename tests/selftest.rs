@@ -0,0 +1,12 @@
+use std::process::Command;
+
+#[test]
+fn zcash_selftest() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wagyu"))
+        .args(["zcash", "selftest"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(!String::from_utf8(output.stdout).unwrap().contains("fail"));
+}
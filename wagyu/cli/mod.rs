@@ -99,6 +99,15 @@ pub enum CLIError {
     #[fail(display = "invalid derived mnemonic for a given private spend key")]
     InvalidMnemonicForPrivateSpendKey,
 
+    #[fail(display = "invalid random number generator seed: {}", _0)]
+    InvalidRngSeed(String),
+
+    #[fail(display = "invalid seed length: {} bytes (expected 16 to 64 bytes)", _0)]
+    InvalidSeedLength(usize),
+
+    #[fail(display = "missing derivation arguments: both --seed and --path are required")]
+    MissingDerivationArguments,
+
     #[fail(display = "{}", _0)]
     PrivateKeyError(PrivateKeyError),
 
@@ -108,6 +117,9 @@ pub enum CLIError {
     #[fail(display = "{}", _0)]
     MnemonicError(MnemonicError),
 
+    #[fail(display = "{} self-test vector(s) failed", _0)]
+    SelfTestFailed(usize),
+
     #[fail(display = "unsupported mnemonic language")]
     UnsupportedLanguage,
 }
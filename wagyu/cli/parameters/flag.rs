@@ -1,3 +1,10 @@
 // Global
 
 pub const JSON: &str = "[json] -j --json 'Prints the generated wallet(s) in JSON format'";
+
+// Zcash
+
+pub const PUBKEY_UNCOMPRESSED_ZCASH: &str =
+    "[pubkey uncompressed] --pubkey-uncompressed 'Prints the uncompressed public key hex of transparent wallets'";
+pub const PRINT_SCHEMA_ZCASH: &str =
+    "[print schema] --print-schema 'Prints the JSON Schema of the generated wallet(s) in JSON format'";
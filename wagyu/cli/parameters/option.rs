@@ -320,3 +320,36 @@ pub const PASSWORD_IMPORT_HD: OptionType = (
     &[],
     &[],
 );
+
+// Derive
+
+pub const NETWORK_DERIVE_ZCASH: OptionType = (
+    "[network] -n --network=[network] 'Derives a wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const PATH_DERIVE_ZCASH: OptionType = (
+    "[path] --path=[\"path\"] 'Derives a wallet at a specified BIP32 derivation path (in quotes), e.g. \"m/44'/133'/0'/0/0\"'",
+    &[],
+    &[],
+    &["seed"],
+);
+pub const SEED_DERIVE_ZCASH: OptionType = (
+    "[seed] --seed=[seed] 'Derives a wallet from a specified hex seed of 16 to 64 bytes'",
+    &[],
+    &[],
+    &["path"],
+);
+pub const COUNT_GEN_VECTORS_ZCASH: OptionType = (
+    "[count] -c --count=[count] 'Generates a specified number of test vectors'",
+    &[],
+    &[],
+    &[],
+);
+pub const SEED_GEN_VECTORS_ZCASH: OptionType = (
+    "[seed] --seed=[seed] 'Generates test vectors from a random number generator of a specified integer seed'",
+    &[],
+    &[],
+    &[],
+);
@@ -5,6 +5,34 @@ use clap::AppSettings;
 // Format
 // (name, about, options, settings)
 
+pub const DERIVE_ZCASH: SubCommandType = (
+    "derive",
+    "Derives a transparent wallet at a BIP32 path of a seed (include -h for more options)",
+    &[
+        option::NETWORK_DERIVE_ZCASH,
+        option::PATH_DERIVE_ZCASH,
+        option::SEED_DERIVE_ZCASH,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const GEN_VECTORS_ZCASH: SubCommandType = (
+    "gen-vectors",
+    "Generates JSON test vectors of transparent private keys from a seeded random number generator",
+    &[option::COUNT_GEN_VECTORS_ZCASH, option::SEED_GEN_VECTORS_ZCASH],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::Hidden,
+    ],
+);
+
 pub const HD_BITCOIN: SubCommandType = (
     "hd",
     "Generates an HD wallet (include -h for more options)",
@@ -185,3 +213,14 @@ pub const IMPORT_HD_ZCASH: SubCommandType = (
         AppSettings::ArgRequiredElseHelp,
     ],
 );
+
+pub const SELFTEST_ZCASH: SubCommandType = (
+    "selftest",
+    "Runs the known-answer tests of this build and reports pass or fail",
+    &[],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+);
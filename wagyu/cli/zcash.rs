@@ -1,8 +1,11 @@
 use crate::cli::{flag, option, subcommand, types::*, CLIError, CLI};
-use crate::model::{ExtendedPrivateKey, ExtendedPublicKey, PrivateKey, PublicKey};
+use crate::model::{
+    crypto::ct_eq_str, ChildIndex, DerivationPathError, ExtendedPrivateKey, ExtendedPublicKey, PrivateKey, PublicKey,
+};
 use crate::zcash::{
-    format::ZcashFormat, Mainnet as ZcashMainnet, Testnet as ZcashTestnet, ZcashAddress, ZcashDerivationPath,
-    ZcashExtendedPrivateKey, ZcashExtendedPublicKey, ZcashNetwork, ZcashPrivateKey, ZcashPublicKey,
+    format::ZcashFormat, signature::verify_context, CompressionPolicy, Mainnet as ZcashMainnet,
+    Testnet as ZcashTestnet, ZcashAddress, ZcashDerivationPath, ZcashExtendedPrivateKey, ZcashExtendedPublicKey,
+    ZcashNetwork, ZcashPrivateKey, ZcashPublicKey, ZcashTransparentExtendedPrivateKey,
 };
 
 use clap::ArgMatches;
@@ -43,6 +46,8 @@ struct ZcashWallet {
     pub private_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_uncompressed: Option<String>,
     pub address: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
@@ -53,6 +58,47 @@ struct ZcashWallet {
 }
 
 impl ZcashWallet {
+    /// Returns the JSON Schema of the JSON output, an array of wallets, whose properties are
+    /// the serialized fields of a wallet. Fields that are always serialized are required.
+    pub fn schema() -> Result<serde_json::Value, CLIError> {
+        let field = || Some(String::new());
+        let wallet = Self {
+            path: field(),
+            extended_private_key: field(),
+            extended_public_key: field(),
+            private_key: field(),
+            public_key: field(),
+            public_key_uncompressed: field(),
+            address: String::new(),
+            format: field(),
+            diversifier: field(),
+            network: field(),
+        };
+
+        let keys = |wallet: &Self| -> Result<Vec<String>, CLIError> {
+            match serde_json::to_value(wallet)? {
+                serde_json::Value::Object(fields) => Ok(fields.keys().cloned().collect()),
+                _ => Ok(vec![]),
+            }
+        };
+        let properties: serde_json::Map<String, serde_json::Value> = keys(&wallet)?
+            .into_iter()
+            .map(|key| (key, serde_json::json!({ "type": "string" })))
+            .collect();
+
+        Ok(serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "Zcash wallets",
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": properties,
+                "required": keys(&Self::default())?,
+                "additionalProperties": false,
+            },
+        }))
+    }
+
     pub fn new<N: ZcashNetwork, R: Rng>(rng: &mut R, format: &ZcashFormat) -> Result<Self, CLIError> {
         let private_key = match format {
             ZcashFormat::P2PKH => ZcashPrivateKey::<N>::new_p2pkh(rng)?,
@@ -92,6 +138,38 @@ impl ZcashWallet {
             format: Some(address.format().to_string()),
             diversifier: address.to_diversifier(),
             network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_seed<N: ZcashNetwork>(seed: &str, path: &str) -> Result<Self, CLIError> {
+        let seed = hex::decode(seed)?;
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(CLIError::InvalidSeedLength(seed.len()));
+        }
+
+        let mut indices = path.split('/');
+        if indices.next() != Some("m") {
+            return Err(DerivationPathError::InvalidDerivationPath(path.into()).into());
+        }
+        let path_indices = indices
+            .map(ChildIndex::from_str)
+            .collect::<Result<Vec<ChildIndex>, DerivationPathError>>()?;
+
+        let extended_private_key = ZcashTransparentExtendedPrivateKey::<N>::new_master(&seed)?.derive(&path_indices)?;
+        let private_key = extended_private_key.to_private_key();
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(&ZcashFormat::P2PKH)?;
+        Ok(Self {
+            path: Some(path.to_string()),
+            extended_private_key: Some(extended_private_key.to_string()),
+            extended_public_key: Some(extended_private_key.to_xpub()),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: address.to_string(),
+            format: Some(address.format().to_string()),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
         })
     }
 
@@ -219,6 +297,10 @@ impl Display for ZcashWallet {
                 Some(public_key) => format!("      {}           {}\n", "Public Key".cyan().bold(), public_key),
                 _ => "".to_owned(),
             },
+            match &self.public_key_uncompressed {
+                Some(public_key) => format!("      {}     {}\n", "Uncompressed Key".cyan().bold(), public_key),
+                _ => "".to_owned(),
+            },
             format!("      {}              {}\n", "Address".cyan().bold(), self.address),
             match &self.format {
                 Some(format) => format!("      {}               {}\n", "Format".cyan().bold(), format),
@@ -241,6 +323,130 @@ impl Display for ZcashWallet {
     }
 }
 
+/// Represents the known-answer vectors of the self-test as (network, private key, address)
+const SELFTEST_VECTORS: [(&str, &str, &str); 5] = [
+    (
+        "mainnet",
+        "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+        "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+    ),
+    (
+        "mainnet",
+        "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+        "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp",
+    ),
+    (
+        "testnet",
+        "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M",
+        "tmWT3bvWCHQkAXXucPjWHqLs9EyWUDdzSuN",
+    ),
+    (
+        "mainnet",
+        "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf",
+    ),
+    (
+        "mainnet",
+        "secret-spending-key-main100nf0tdkdumdx7cjmndaagu0htkgxszq9hjrhlspdu7ppd48yg8qxd0yqa",
+        "zs1vvdj0st065ngdruymdcdy63duuavjeww3a2yyeu5tsqj2azhvwgkcaw9ngggfas6h4z4whnkpwz",
+    ),
+];
+
+/// Returns `true` if the private key round-trips and derives the expected address.
+fn selftest_vector<N: ZcashNetwork>(private_key: &str, expected_address: &str) -> bool {
+    let format = match ZcashAddress::<N>::from_str(expected_address) {
+        Ok(address) => match address.format() {
+            ZcashFormat::Sapling(_) => match ZcashAddress::<N>::get_diversifier(expected_address) {
+                Ok(diversifier) => ZcashFormat::Sapling(Some(diversifier)),
+                _ => return false,
+            },
+            format => format,
+        },
+        _ => return false,
+    };
+
+    match ZcashPrivateKey::<N>::from_str(private_key) {
+        Ok(key) => {
+            ct_eq_str(&key.to_string(), private_key)
+                && key.to_address(&format).ok().map(|address| address.to_string()) == Some(expected_address.into())
+        }
+        _ => false,
+    }
+}
+
+/// Runs the known-answer vectors and prints the result of each, failing if any vector fails
+#[cfg_attr(tarpaulin, skip)]
+fn selftest() -> Result<(), CLIError> {
+    let context = verify_context();
+    match context {
+        true => println!("{} secp256k1 context", "pass".green().bold()),
+        false => println!("{} secp256k1 context", "fail".red().bold()),
+    };
+
+    let failures = SELFTEST_VECTORS
+        .iter()
+        .filter(|(network, private_key, address)| {
+            let passed = match *network {
+                "testnet" => selftest_vector::<ZcashTestnet>(private_key, address),
+                _ => selftest_vector::<ZcashMainnet>(private_key, address),
+            };
+            match passed {
+                true => println!("{} {} {}", "pass".green().bold(), network, address),
+                false => println!("{} {} {}", "fail".red().bold(), network, address),
+            };
+            !passed
+        })
+        .count()
+        + !context as usize;
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(CLIError::SelfTestFailed(failures)),
+    }
+}
+
+/// Represents a test vector of a transparent private key: its WIF, hex secret key, network,
+/// compression, and P2PKH address
+type TestVector = (String, String, String, bool, String);
+
+/// Returns a test vector of a random transparent private key of network `N` and the given compression.
+fn test_vector<N: ZcashNetwork, R: Rng>(rng: &mut R, compressed: bool) -> Result<TestVector, CLIError> {
+    let policy = match compressed {
+        true => CompressionPolicy::Compressed,
+        false => CompressionPolicy::Uncompressed,
+    };
+    let private_key = ZcashPrivateKey::<N>::new_with_policy(rng, policy)?;
+    let wif = private_key.to_string();
+    let (_, secret_key, _) = ZcashPrivateKey::<N>::decode_wif_parts(&wif)?;
+    let address = private_key.to_address(&ZcashFormat::P2PKH)?.to_string();
+    Ok((wif, hex::encode(secret_key), N::NAME.into(), compressed, address))
+}
+
+/// Prints `count` test vectors as JSON, of random networks and compressions, generated by a random
+/// number generator of the given seed, so that the same seed always prints the same test vectors
+#[cfg_attr(tarpaulin, skip)]
+fn gen_vectors(count: usize, seed: Option<&str>) -> Result<(), CLIError> {
+    let seed = match seed.map(u64::from_str) {
+        Some(Ok(seed)) => seed,
+        Some(Err(_)) => return Err(CLIError::InvalidRngSeed(seed.unwrap_or_default().into())),
+        None => 0,
+    };
+
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let vectors = (0..count)
+        .map(|_| {
+            let compressed = rng.gen::<bool>();
+            match rng.gen::<bool>() {
+                true => test_vector::<ZcashMainnet, _>(rng, compressed),
+                false => test_vector::<ZcashTestnet, _>(rng, compressed),
+            }
+        })
+        .collect::<Result<Vec<TestVector>, CLIError>>()?;
+
+    println!("{}", serde_json::to_string_pretty(&vectors)?);
+    Ok(())
+}
+
 /// Represents options for a Zcash wallet
 #[derive(Clone, Debug, Serialize)]
 pub struct ZcashOptions {
@@ -250,6 +456,8 @@ pub struct ZcashOptions {
     format: ZcashFormat,
     json: bool,
     network: String,
+    print_schema: bool,
+    pubkey_uncompressed: bool,
     subcommand: Option<String>,
     // HD and Import HD subcommands
     account: u32,
@@ -267,6 +475,8 @@ pub struct ZcashOptions {
     address: Option<String>,
     private: Option<String>,
     public: Option<String>,
+    // Derive subcommand
+    seed: Option<String>,
 }
 
 impl Default for ZcashOptions {
@@ -278,6 +488,8 @@ impl Default for ZcashOptions {
             format: ZcashFormat::P2PKH,
             json: false,
             network: "mainnet".into(),
+            print_schema: false,
+            pubkey_uncompressed: false,
             subcommand: None,
             // HD and Import HD subcommands
             account: 0,
@@ -295,6 +507,8 @@ impl Default for ZcashOptions {
             address: None,
             private: None,
             public: None,
+            // Derive subcommand
+            seed: None,
         }
     }
 }
@@ -313,8 +527,12 @@ impl ZcashOptions {
             "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
             "json" => self.json(arguments.is_present(option)),
             "network" => self.network(arguments.value_of(option)),
+            "path" => self.path(arguments.value_of(option)),
+            "print schema" => self.print_schema(arguments.is_present(option)),
             "private" => self.private(arguments.value_of(option)),
             "public" => self.public(arguments.value_of(option)),
+            "pubkey uncompressed" => self.pubkey_uncompressed(arguments.is_present(option)),
+            "seed" => self.seed(arguments.value_of(option)),
             _ => (),
         });
     }
@@ -442,6 +660,32 @@ impl ZcashOptions {
         }
     }
 
+    /// Sets `path` to the specified derivation path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn path(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.path = Some(path.into());
+        }
+    }
+
+    /// Sets `print_schema` to the specified boolean value, overriding its previous state.
+    fn print_schema(&mut self, argument: bool) {
+        self.print_schema = argument;
+    }
+
+    /// Sets `pubkey_uncompressed` to the specified boolean value, overriding its previous state.
+    fn pubkey_uncompressed(&mut self, argument: bool) {
+        self.pubkey_uncompressed = argument;
+    }
+
+    /// Sets `seed` to the specified hex seed, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn seed(&mut self, argument: Option<&str>) {
+        if let Some(seed) = argument {
+            self.seed = Some(seed.into());
+        }
+    }
+
     /// Returns the derivation path with the specified account, index, and path.
     /// If `default` is enabled, then return the default path if no derivation was provided.
     fn to_derivation_path(&self, default: bool) -> Option<String> {
@@ -471,7 +715,7 @@ impl CLI for ZcashCLI {
 
     const NAME: NameType = "zcash";
     const ABOUT: AboutType = "Generates a Zcash wallet (include -h for more options)";
-    const FLAGS: &'static [FlagType] = &[flag::JSON];
+    const FLAGS: &'static [FlagType] = &[flag::JSON, flag::PRINT_SCHEMA_ZCASH, flag::PUBKEY_UNCOMPRESSED_ZCASH];
     const OPTIONS: &'static [OptionType] = &[
         option::COUNT,
         option::DIVERSIFIER_ZCASH,
@@ -479,36 +723,77 @@ impl CLI for ZcashCLI {
         option::NETWORK_ZCASH,
     ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::DERIVE_ZCASH,
+        subcommand::GEN_VECTORS_ZCASH,
         subcommand::HD_ZCASH,
         subcommand::IMPORT_ZCASH,
         subcommand::IMPORT_HD_ZCASH,
+        subcommand::SELFTEST_ZCASH,
     ];
 
     /// Handle all CLI arguments and flags for Zcash
     #[cfg_attr(tarpaulin, skip)]
     fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
         let mut options = ZcashOptions::default();
-        options.parse(arguments, &["count", "diversifier", "format", "json", "network"]);
+        options.parse(
+            arguments,
+            &[
+                "count",
+                "diversifier",
+                "format",
+                "json",
+                "network",
+                "print schema",
+                "pubkey uncompressed",
+            ],
+        );
 
         match arguments.subcommand() {
+            ("derive", Some(arguments)) => {
+                options.subcommand = Some("derive".into());
+                options.parse(arguments, &["json", "network", "path", "seed"]);
+            }
+            ("gen-vectors", Some(arguments)) => {
+                options.subcommand = Some("gen-vectors".into());
+                options.parse(arguments, &["count", "seed"]);
+            }
             ("hd", Some(arguments)) => {
                 options.subcommand = Some("hd".into());
-                options.parse(arguments, &["count", "diversifier", "format", "json", "network"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "count",
+                        "diversifier",
+                        "format",
+                        "json",
+                        "network",
+                        "pubkey uncompressed",
+                    ],
+                );
                 options.parse(arguments, &["derivation"]);
             }
             ("import", Some(arguments)) => {
                 options.subcommand = Some("import".into());
-                options.parse(arguments, &["diversifier", "format", "json", "network"]);
+                options.parse(
+                    arguments,
+                    &["diversifier", "format", "json", "network", "pubkey uncompressed"],
+                );
                 options.parse(arguments, &["address", "private", "public"]);
             }
             ("import-hd", Some(arguments)) => {
                 options.subcommand = Some("import-hd".into());
-                options.parse(arguments, &["diversifier", "format", "json", "network"]);
+                options.parse(
+                    arguments,
+                    &["diversifier", "format", "json", "network", "pubkey uncompressed"],
+                );
                 options.parse(
                     arguments,
                     &["account", "derivation", "extended private", "extended public", "index"],
                 );
             }
+            ("selftest", Some(_)) => {
+                options.subcommand = Some("selftest".into());
+            }
             _ => {}
         };
 
@@ -519,8 +804,12 @@ impl CLI for ZcashCLI {
     #[cfg_attr(tarpaulin, skip)]
     fn print(options: Self::Options) -> Result<(), CLIError> {
         fn output<N: ZcashNetwork>(options: ZcashOptions) -> Result<(), CLIError> {
-            let wallets =
+            let mut wallets =
                 match options.subcommand.as_ref().map(String::as_str) {
+                    Some("derive") => match (&options.seed, &options.path) {
+                        (Some(seed), Some(path)) => vec![ZcashWallet::from_seed::<N>(seed, path)?],
+                        _ => return Err(CLIError::MissingDerivationArguments),
+                    },
                     Some("hd") => {
                         let path = options.to_derivation_path(true).unwrap();
                         (0..options.count)
@@ -584,6 +873,16 @@ impl CLI for ZcashCLI {
                         .collect(),
                 };
 
+            if options.pubkey_uncompressed {
+                wallets.iter_mut().for_each(|wallet| {
+                    wallet.public_key_uncompressed = wallet
+                        .public_key
+                        .as_ref()
+                        .and_then(|public_key| ZcashPublicKey::<N>::from_str(public_key).ok())
+                        .and_then(|public_key| public_key.to_hex(false));
+                });
+            }
+
             match options.json {
                 true => println!("{}\n", serde_json::to_string_pretty(&wallets)?),
                 false => wallets.iter().for_each(|wallet| println!("{}\n", wallet)),
@@ -592,6 +891,19 @@ impl CLI for ZcashCLI {
             Ok(())
         }
 
+        if options.print_schema {
+            println!("{}\n", serde_json::to_string_pretty(&ZcashWallet::schema()?)?);
+            return Ok(());
+        }
+
+        if let Some("selftest") = options.subcommand.as_ref().map(String::as_str) {
+            return selftest();
+        }
+
+        if let Some("gen-vectors") = options.subcommand.as_deref() {
+            return gen_vectors(options.count, options.seed.as_deref());
+        }
+
         match options.network.as_str() {
             "testnet" => output::<ZcashTestnet>(options),
             _ => output::<ZcashMainnet>(options),
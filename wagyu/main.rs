@@ -9,9 +9,19 @@ use wagyu::cli::zcash::ZcashCLI;
 use wagyu::cli::{CLIError, CLI};
 
 use clap::{App, AppSettings};
+use std::process;
 
+/// Runs the command, printing the readable message of an error and exiting unsuccessfully on failure.
 #[cfg_attr(tarpaulin, skip)]
-fn main() -> Result<(), CLIError> {
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("Error: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+fn run() -> Result<(), CLIError> {
     let arguments = App::new("wagyu")
         .version("v0.6.0")
         .about("Generate a wallet for Bitcoin, Ethereum, Monero, and Zcash")
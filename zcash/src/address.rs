@@ -1,21 +1,40 @@
+use crate::encoder::{encode_hash, AddressEncoder, Base58P2PKH, Base58P2SH, Bech32Sapling};
+use crate::encrypted_wallet::{argon2id, Argon2Parameters};
 use crate::format::ZcashFormat;
 use crate::librustzcash::sapling_crypto::primitives::Diversifier;
 use crate::librustzcash::JUBJUB;
 use crate::network::ZcashNetwork;
-use crate::private_key::ZcashPrivateKey;
+use crate::private_key::{P2PKHSpendingKey, ZcashPrivateKey};
 use crate::public_key::{P2PKHViewingKey, SaplingFullViewingKey, SproutViewingKey, ZcashPublicKey};
 use wagyu_model::{
     crypto::{checksum, hash160},
-    Address, AddressError, PrivateKey,
+    Address, AddressError, PrivateKey, PrivateKeyError,
 };
 
 use base58::{FromBase58, ToBase58};
-use bech32::{Bech32, FromBase32, ToBase32};
+use bech32::{Bech32, FromBase32};
 use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
 use curve25519_dalek::scalar::Scalar;
 use rand::{rngs::StdRng, Rng};
 use rand_core::SeedableRng;
-use std::{convert::TryFrom, fmt, marker::PhantomData, str, str::FromStr};
+use std::{collections::BTreeMap, convert::TryFrom, fmt, marker::PhantomData, str, str::FromStr};
+
+/// The script opcode that fails unless the transaction locktime is at least the given locktime (BIP65)
+const OP_CHECKLOCKTIMEVERIFY: u8 = 0xb1;
+/// The script opcode that removes the top stack item
+const OP_DROP: u8 = 0x75;
+/// The script opcode that verifies a signature of the transaction against a public key
+const OP_CHECKSIG: u8 = 0xac;
+
+/// The Argon2id salt of addresses derived from an identity
+const IDENTITY_SALT: &[u8] = b"wagyu zcash identity address";
+/// The Argon2id cost of addresses derived from an identity, 19 MiB of memory over 2 passes in 1 lane.
+/// It is part of the derivation, so it is fixed independently of the cost of encrypted wallet files.
+const IDENTITY_ARGON2_PARAMETERS: Argon2Parameters = Argon2Parameters {
+    memory: 19 * 1024,
+    iterations: 2,
+    parallelism: 1,
+};
 
 /// Represents a Zcash address
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -54,31 +73,109 @@ impl<N: ZcashNetwork> Address for ZcashAddress<N> {
     }
 }
 
+/// A hash function from a serialized public key to the 20-byte payload of a transparent address
+pub type HashFn = fn(&[u8]) -> Vec<u8>;
+
 impl<N: ZcashNetwork> ZcashAddress<N> {
     /// Returns a transparent address from a given Zcash public key.
     pub fn p2pkh(public_key: &P2PKHViewingKey) -> Self {
-        let mut address = [0u8; 26];
-        address[0..2].copy_from_slice(&N::to_address_prefix(&ZcashFormat::P2PKH));
-        address[2..22].copy_from_slice(&hash160(&match public_key.compressed {
+        let mut hash = [0u8; 20];
+        hash.copy_from_slice(&hash160(&match public_key.compressed {
             true => public_key.public_key.serialize().to_vec(),
             false => public_key.public_key.serialize_uncompressed().to_vec(),
         }));
 
-        let sum = &checksum(&address[0..22])[0..4];
-        address[22..26].copy_from_slice(sum);
-
         Self {
-            address: address.to_base58(),
+            address: encode_hash::<N>(&ZcashFormat::P2PKH, &hash),
             format: ZcashFormat::P2PKH,
             _network: PhantomData,
         }
     }
 
+    /// Returns a transparent address from a given Zcash public key, hashed with the given
+    /// hash function in place of hash160 for forks that define the public key hash differently.
+    pub fn p2pkh_with_hash(public_key: &P2PKHViewingKey, hash: HashFn) -> Result<Self, AddressError> {
+        let hash = hash(&match public_key.compressed {
+            true => public_key.public_key.serialize().to_vec(),
+            false => public_key.public_key.serialize_uncompressed().to_vec(),
+        });
+
+        Self::from_payload(&hash, &ZcashFormat::P2PKH)
+    }
+
     /// Returns a P2SH address.
     pub fn p2sh() -> Self {
         unimplemented!("p2sh addresses are unimplemented");
     }
 
+    /// Returns the P2SH address of the CheckLockTimeVerify redeem script of the given P2PKH public key,
+    /// whose funds are spendable by the public key from the given block height (below 500,000,000)
+    /// or Unix timestamp.
+    pub fn p2sh_cltv(public_key: &ZcashPublicKey<N>, locktime: u32) -> Result<Self, AddressError> {
+        let redeem_script = Self::cltv_redeem_script(public_key, locktime)?;
+        Self::from_payload(&hash160(&redeem_script), &ZcashFormat::P2SH)
+    }
+
+    /// Returns the P2SH address of the pay-to-pubkey redeem script `<public key> OP_CHECKSIG`
+    /// of the given P2PKH public key, serialized in the compression of the public key.
+    pub fn p2sh_p2pk(public_key: &ZcashPublicKey<N>) -> Result<Self, AddressError> {
+        let public_key = match public_key {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => match viewing_key.compressed {
+                true => viewing_key.public_key.serialize().to_vec(),
+                false => viewing_key.public_key.serialize_uncompressed().to_vec(),
+            },
+            _ => {
+                return Err(AddressError::IncompatibleFormats(
+                    "shielded public key".into(),
+                    ZcashFormat::P2SH.to_string(),
+                ))
+            }
+        };
+
+        let mut script = vec![public_key.len() as u8];
+        script.extend(public_key);
+        script.push(OP_CHECKSIG);
+        Self::from_payload(&hash160(&script), &ZcashFormat::P2SH)
+    }
+
+    /// Returns the redeem script `<locktime> OP_CHECKLOCKTIMEVERIFY OP_DROP <public key> OP_CHECKSIG`
+    /// of the given P2PKH public key, with the locktime encoded as a minimal script number.
+    pub fn cltv_redeem_script(public_key: &ZcashPublicKey<N>, locktime: u32) -> Result<Vec<u8>, AddressError> {
+        // A locktime of zero is satisfied by every transaction, so it does not lock anything.
+        if locktime == 0 {
+            return Err(AddressError::Message("invalid locktime: 0".into()));
+        }
+
+        let public_key = match public_key {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => match viewing_key.compressed {
+                true => viewing_key.public_key.serialize().to_vec(),
+                false => viewing_key.public_key.serialize_uncompressed().to_vec(),
+            },
+            _ => {
+                return Err(AddressError::IncompatibleFormats(
+                    "shielded public key".into(),
+                    ZcashFormat::P2SH.to_string(),
+                ))
+            }
+        };
+
+        // Script numbers are little-endian with the most significant bit of the last byte as the sign.
+        let mut locktime = locktime.to_le_bytes().to_vec();
+        while locktime.last() == Some(&0) {
+            locktime.pop();
+        }
+        if locktime.last().is_some_and(|byte| byte & 0x80 != 0) {
+            locktime.push(0);
+        }
+
+        let mut script = vec![locktime.len() as u8];
+        script.extend(locktime);
+        script.extend(&[OP_CHECKLOCKTIMEVERIFY, OP_DROP, public_key.len() as u8]);
+        script.extend(public_key);
+        script.push(OP_CHECKSIG);
+        Ok(script)
+    }
+
     /// Returns a shielded address from a given Zcash public key.
     pub fn sprout(public_key: &SproutViewingKey) -> Result<Self, AddressError> {
         let pk = &Scalar::from_bits(public_key.key_b) * &ED25519_BASEPOINT_TABLE;
@@ -122,14 +219,63 @@ impl<N: ZcashNetwork> ZcashAddress<N> {
 
         let mut checked_data = vec![0; 43];
         checked_data[..11].copy_from_slice(&diversifier);
-        address.pk_d.write(checked_data[11..].as_mut())?;
+        address.pk_d.write(&mut checked_data[11..])?;
+
+        Self::from_payload(&checked_data, &ZcashFormat::Sapling(Some(diversifier)))
+    }
 
-        let format = ZcashFormat::Sapling(Some(diversifier));
-        let prefix = N::to_address_prefix(&format);
+    /// Returns the P2PKH or P2SH address of a given public key or script hash.
+    pub fn from_hash160(hash: [u8; 20], format: &ZcashFormat) -> Result<Self, AddressError> {
+        match format {
+            ZcashFormat::P2PKH | ZcashFormat::P2SH => Self::from_payload(&hash, format),
+            _ => Err(AddressError::IncompatibleFormats(
+                format.to_string(),
+                "hash160 encoding".into(),
+            )),
+        }
+    }
+
+    /// Returns the address of the given format of a mini private key, as redeemed from a paper wallet.
+    pub fn from_minikey(minikey: &str, format: &ZcashFormat) -> Result<Self, AddressError> {
+        ZcashPrivateKey::<N>::from_minikey(minikey)?.to_address(format)
+    }
+
+    /// Returns the compressed P2PKH address of a given email and service, whose secret key is the
+    /// Argon2id hash of the email and service, separated by a NUL byte, under a fixed salt.
+    /// This is a convenience for deterministic addresses bound to an identity only. Anyone who knows
+    /// the email and service can derive the private key of the address, so it secures no funds.
+    pub fn from_identity(email: &str, service: &str) -> Result<Self, AddressError> {
+        let password = format!("{}\0{}", email, service);
+        let secret_key = argon2id(
+            password.as_bytes(),
+            IDENTITY_SALT,
+            &[],
+            &[],
+            &IDENTITY_ARGON2_PARAMETERS,
+            32,
+        )
+        .map_err(|error| AddressError::Crate("argon2", format!("{:?}", error)))?;
+        let secret_key = secp256k1::SecretKey::from_slice(&secret_key).map_err(PrivateKeyError::from)?;
+        ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, true)).to_address(&ZcashFormat::P2PKH)
+    }
+
+    /// Returns the address of a given payload, encoded with the address encoder of the format.
+    pub fn from_payload(payload: &[u8], format: &ZcashFormat) -> Result<Self, AddressError> {
+        let address = match format {
+            ZcashFormat::P2PKH => Base58P2PKH::encode::<N>(payload)?,
+            ZcashFormat::P2SH => Base58P2SH::encode::<N>(payload)?,
+            ZcashFormat::Sapling(_) => Bech32Sapling::encode::<N>(payload)?,
+            ZcashFormat::Sprout => {
+                return Err(AddressError::IncompatibleFormats(
+                    format.to_string(),
+                    "payload encoding".into(),
+                ))
+            }
+        };
 
         Ok(Self {
-            address: Bech32::new(String::from(str::from_utf8(&prefix)?), checked_data.to_base32())?.to_string(),
-            format: ZcashFormat::Sapling(Some(diversifier)),
+            address,
+            format: format.clone(),
             _network: PhantomData,
         })
     }
@@ -156,6 +302,353 @@ impl<N: ZcashNetwork> ZcashAddress<N> {
     pub fn format(&self) -> ZcashFormat {
         self.format.clone()
     }
+
+    /// Returns a payment request URI of the form `zcash:<address>?amount=<amount>&memo=<memo>`.
+    /// The amount is denominated in ZEC and must be finite and non-negative,
+    /// and the memo is percent-encoded.
+    pub fn to_uri(&self, amount: Option<f64>, memo: Option<&str>) -> Result<String, AddressError> {
+        let mut parameters = vec![];
+        if let Some(amount) = amount {
+            if !amount.is_finite() || amount < 0.0 {
+                return Err(AddressError::Message(format!("invalid payment amount: {}", amount)));
+            }
+            parameters.push(format!("amount={}", amount));
+        }
+        if let Some(memo) = memo {
+            parameters.push(format!("memo={}", percent_encode(memo)));
+        }
+
+        match parameters.is_empty() {
+            true => Ok(format!("zcash:{}", self.address)),
+            false => Ok(format!("zcash:{}?{}", self.address, parameters.join("&"))),
+        }
+    }
+
+    /// Returns the address, amount, and memo of a given payment request URI.
+    pub fn from_uri(uri: &str) -> Result<(Self, Option<f64>, Option<String>), AddressError> {
+        // The scheme is sliced with `get`, as byte 6 of a non-ASCII uri may not be a character boundary.
+        let rest = match uri.get(..6).is_some_and(|scheme| scheme.eq_ignore_ascii_case("zcash:")) {
+            true => &uri[6..],
+            false => return Err(AddressError::InvalidAddress(uri.into())),
+        };
+
+        let mut parts = rest.splitn(2, '?');
+        let address = Self::from_str(parts.next().unwrap_or(""))?;
+
+        let mut amount = None;
+        let mut memo = None;
+        if let Some(query) = parts.next() {
+            for parameter in query.split('&') {
+                let mut pair = parameter.splitn(2, '=');
+                match (pair.next(), pair.next()) {
+                    (Some("amount"), Some(value)) if amount.is_none() => {
+                        let value = value
+                            .parse::<f64>()
+                            .map_err(|_| AddressError::Message(format!("invalid payment amount: {}", value)))?;
+                        if !value.is_finite() || value < 0.0 {
+                            return Err(AddressError::Message(format!("invalid payment amount: {}", value)));
+                        }
+                        amount = Some(value);
+                    }
+                    (Some("memo"), Some(value)) if memo.is_none() => memo = Some(percent_decode(value)?),
+                    (Some("amount"), _) | (Some("memo"), _) => {
+                        return Err(AddressError::Message(format!("invalid uri parameter: {}", parameter)))
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok((address, amount, memo))
+    }
+
+    /// Returns the address abbreviated to its first `prefix_len` and last `suffix_len` characters,
+    /// joined by an ellipsis, e.g. "t1Rxy...zQTKp". An address no longer than the two parts is
+    /// returned whole.
+    pub fn to_short(&self, prefix_len: usize, suffix_len: usize) -> Result<String, AddressError> {
+        let length = self.address.len();
+        if prefix_len > length || suffix_len > length || prefix_len + suffix_len > length {
+            return Err(AddressError::Message(format!(
+                "abbreviation of {} and {} characters exceeds the address length of {}",
+                prefix_len, suffix_len, length
+            )));
+        }
+
+        match prefix_len + suffix_len == length {
+            true => Ok(self.address.clone()),
+            false => Ok(format!(
+                "{}...{}",
+                &self.address[..prefix_len],
+                &self.address[length - suffix_len..]
+            )),
+        }
+    }
+
+    /// Returns the legacy Sprout address of a given string, validating its base58check encoding,
+    /// its length of a 2-byte prefix, 32-byte paying key, and 32-byte transmission key, and its network.
+    pub fn parse_sprout(address: &str) -> Result<Self, AddressError> {
+        let data = address.from_base58()?;
+        if data.len() != 70 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        let (payload, expected) = data.split_at(66);
+        let found = &checksum(payload)[0..4];
+        if expected != found {
+            return Err(AddressError::InvalidChecksum(expected.to_base58(), found.to_base58()));
+        }
+
+        let prefix = payload[0..2].to_vec();
+        if prefix != N::to_address_prefix(&ZcashFormat::Sprout) {
+            return Err(AddressError::InvalidPrefix(prefix));
+        }
+
+        Ok(Self {
+            address: address.into(),
+            format: ZcashFormat::Sprout,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns `true` if the given string is a valid P2SH address with a 20-byte redeem script hash.
+    pub fn is_valid_p2sh(address: &str) -> bool {
+        match Self::from_str(address) {
+            Ok(address) => address.format == ZcashFormat::P2SH,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the address is a transparent address.
+    pub fn is_transparent(&self) -> bool {
+        self.format.is_transparent()
+    }
+
+    /// Returns `true` if the address is a shielded address.
+    pub fn is_shielded(&self) -> bool {
+        self.format.is_shielded()
+    }
+
+    /// Returns `true` if the address is a P2PKH address.
+    pub fn is_p2pkh(&self) -> bool {
+        self.format == ZcashFormat::P2PKH
+    }
+
+    /// Returns `true` if the address is a P2SH address.
+    pub fn is_p2sh(&self) -> bool {
+        self.format == ZcashFormat::P2SH
+    }
+}
+
+/// Returns the result of parsing each of the given addresses on network `N`, in order, ignoring
+/// surrounding whitespace, so that a list of addresses can be audited in one call.
+pub fn validate_addresses<N: ZcashNetwork>(addresses: &[&str]) -> Vec<Result<ZcashAddress<N>, AddressError>> {
+    addresses
+        .iter()
+        .map(|address| ZcashAddress::<N>::from_str(address.trim()))
+        .collect()
+}
+
+/// Returns the address of the given format of a WIF on network `N`, in the compression of the WIF.
+/// A WIF of another network is rejected rather than yielding an address of network `N`.
+pub fn wif_to_address<N: ZcashNetwork>(wif: &str, format: &ZcashFormat) -> Result<ZcashAddress<N>, AddressError> {
+    ZcashPrivateKey::<N>::from_str(wif)?.to_address(format)
+}
+
+/// Returns whether each of the given pairs of an encoded private key and its expected address on
+/// network `N` match, in order, deriving each address in the format of the expected address,
+/// so that a stored backup can be checked for transcription errors. A pair that fails to parse does not match.
+pub fn verify_pairs<N: ZcashNetwork>(pairs: &[(String, String)]) -> Vec<bool> {
+    pairs
+        .iter()
+        .map(|(private_key, expected_address)| {
+            match (
+                ZcashPrivateKey::<N>::from_str(private_key.trim()),
+                ZcashAddress::<N>::from_str(expected_address.trim()),
+            ) {
+                (Ok(private_key), Ok(expected_address)) => {
+                    private_key.to_address(&expected_address.format()).ok() == Some(expected_address)
+                }
+                _ => false,
+            }
+        })
+        .collect()
+}
+
+/// The property by which `assert_unique_addresses` compares private keys
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Uniqueness {
+    /// Private keys are duplicates if they have the same address
+    Address,
+    /// Private keys are duplicates if they have the same secret key, regardless of their compression
+    Secret,
+}
+
+/// Returns an error naming the first two of the given private keys that are duplicates by the given property.
+/// Sapling private keys are compared by their spending key in either mode, as their addresses
+/// are derived with random diversifiers.
+pub fn assert_unique_addresses<N: ZcashNetwork>(
+    private_keys: &[ZcashPrivateKey<N>],
+    by: Uniqueness,
+) -> Result<(), AddressError> {
+    let mut seen = BTreeMap::new();
+    for (index, private_key) in private_keys.iter().enumerate() {
+        let key = match (private_key, by) {
+            (ZcashPrivateKey::<N>::P2PKH(spending_key), Uniqueness::Secret) => {
+                spending_key.to_secp256k1_secret_key()[..].to_vec()
+            }
+            (ZcashPrivateKey::<N>::Sprout(spending_key), Uniqueness::Secret) => spending_key.spending_key.to_vec(),
+            (ZcashPrivateKey::<N>::Sapling(spending_key), _) => spending_key.to_bytes().to_vec(),
+            (ZcashPrivateKey::<N>::P2PKH(_), Uniqueness::Address) => {
+                private_key.to_address(&ZcashFormat::P2PKH)?.to_string().into_bytes()
+            }
+            (ZcashPrivateKey::<N>::Sprout(_), Uniqueness::Address) => {
+                private_key.to_address(&ZcashFormat::Sprout)?.to_string().into_bytes()
+            }
+            (ZcashPrivateKey::<N>::P2SH(_), _) => private_key.to_string().into_bytes(),
+        };
+
+        if let Some(first) = seen.insert(key, index) {
+            return Err(AddressError::Message(format!(
+                "duplicate private keys at indices {} and {}",
+                first, index
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the approximate probability that generating `n` transparent addresses yields at least
+/// two with the same 160-bit hash, computed with the birthday bound 1 - e^(-n(n - 1) / 2^161).
+/// This is for illustration; it assumes uniformly random hashes.
+pub fn collision_probability(n: u64) -> f64 {
+    let pairs = n as f64 * n.saturating_sub(1) as f64 / 2.0;
+    -(-pairs / 2f64.powi(160)).exp_m1()
+}
+
+/// Returns the percent-encoding of a string, leaving only unreserved URI characters as is.
+fn percent_encode(input: &str) -> String {
+    input
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Returns the decoding of a percent-encoded string.
+fn percent_decode(input: &str) -> Result<String, AddressError> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let byte = match bytes.get(i + 1..i + 3) {
+                    Some(hex) => str::from_utf8(hex)
+                        .ok()
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+                    None => None,
+                };
+                match byte {
+                    Some(byte) => output.push(byte),
+                    None => return Err(AddressError::Message(format!("invalid percent-encoding: {}", input))),
+                }
+                i += 3;
+            }
+            byte => {
+                output.push(byte);
+                i += 1;
+            }
+        }
+    }
+    Ok(String::from_utf8(output)?)
+}
+
+/// Represents a Zcash transparent address (P2PKH or P2SH)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZcashTransparentAddress<N: ZcashNetwork>(ZcashAddress<N>);
+
+impl<N: ZcashNetwork> ZcashTransparentAddress<N> {
+    /// Returns the 20-byte public key hash or script hash encoded in the transparent address.
+    pub fn to_hash(&self) -> Result<[u8; 20], AddressError> {
+        let data = self.0.address.from_base58()?;
+        if data.len() != 26 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        let mut hash = [0u8; 20];
+        hash.copy_from_slice(&data[2..22]);
+        Ok(hash)
+    }
+
+    /// Returns a reference to the underlying Zcash address.
+    pub fn as_address(&self) -> &ZcashAddress<N> {
+        &self.0
+    }
+}
+
+impl<N: ZcashNetwork> TryFrom<ZcashAddress<N>> for ZcashTransparentAddress<N> {
+    type Error = AddressError;
+
+    fn try_from(address: ZcashAddress<N>) -> Result<Self, Self::Error> {
+        match address.is_transparent() {
+            true => Ok(Self(address)),
+            false => Err(AddressError::IncompatibleFormats(
+                address.format.to_string(),
+                "transparent".into(),
+            )),
+        }
+    }
+}
+
+impl<N: ZcashNetwork> From<ZcashTransparentAddress<N>> for ZcashAddress<N> {
+    fn from(address: ZcashTransparentAddress<N>) -> Self {
+        address.0
+    }
+}
+
+impl<N: ZcashNetwork> fmt::Display for ZcashTransparentAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Represents a Zcash shielded address (Sprout or Sapling)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZcashShieldedAddress<N: ZcashNetwork>(ZcashAddress<N>);
+
+impl<N: ZcashNetwork> ZcashShieldedAddress<N> {
+    /// Returns a reference to the underlying Zcash address.
+    pub fn as_address(&self) -> &ZcashAddress<N> {
+        &self.0
+    }
+}
+
+impl<N: ZcashNetwork> TryFrom<ZcashAddress<N>> for ZcashShieldedAddress<N> {
+    type Error = AddressError;
+
+    fn try_from(address: ZcashAddress<N>) -> Result<Self, Self::Error> {
+        match address.is_shielded() {
+            true => Ok(Self(address)),
+            false => Err(AddressError::IncompatibleFormats(
+                address.format.to_string(),
+                "shielded".into(),
+            )),
+        }
+    }
+}
+
+impl<N: ZcashNetwork> From<ZcashShieldedAddress<N>> for ZcashAddress<N> {
+    fn from(address: ZcashShieldedAddress<N>) -> Self {
+        address.0
+    }
+}
+
+impl<N: ZcashNetwork> fmt::Display for ZcashShieldedAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 impl<'a, N: ZcashNetwork> TryFrom<&'a str> for ZcashAddress<N> {
@@ -176,35 +669,35 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
 
         // Transparent
         if &address[0..=0] == "t" && address.len() < 40 {
-            match &address[1..=1] {
-                "1" | "m" => {
-                    let data = address.from_base58()?;
-                    if data.len() != 26 {
-                        return Err(AddressError::InvalidByteLength(data.len()));
-                    }
-
-                    // Check that the network bytes correspond with the correct network.
-                    let _ = N::from_address_prefix(&data[0..2].to_vec())?;
-                    let format = ZcashFormat::from_address_prefix(&data[0..2].to_vec())?;
-
-                    return Ok(Self {
-                        address: address.into(),
-                        format,
-                        _network: PhantomData,
-                    });
-                }
-                "3" | "2" => {
-                    unimplemented!("");
-                }
+            let format = match &address[1..=1] {
+                "1" | "m" => ZcashFormat::P2PKH,
+                "3" | "2" => ZcashFormat::P2SH,
                 _ => return Err(AddressError::InvalidAddress(address.into())),
+            };
+
+            // The public key or redeem script hash following the prefix must be exactly 20 bytes.
+            let (payload, _) = match format {
+                ZcashFormat::P2PKH => Base58P2PKH::decode::<N>(address)?,
+                _ => Base58P2SH::decode::<N>(address)?,
+            };
+            if payload.len() != 20 {
+                return Err(AddressError::InvalidByteLength(payload.len()));
             }
+
+            return Ok(Self {
+                address: address.into(),
+                format,
+                _network: PhantomData,
+            });
         }
 
         // Shielded
         if &address[0..=0] == "z" && address.len() > 77 {
-            if &address[0..12] == "ztestsapling" && address.len() > 87 {
-                // Check that the network bytes correspond with the correct network.
-                let _ = N::from_address_prefix(&address[0..12].as_bytes().to_vec())?;
+            if (&address[0..12] == "ztestsapling" && address.len() > 87)
+                || (address.starts_with("zregtestsapling") && address.len() > 90)
+                || &address[0..2] == "zs"
+            {
+                let _ = Bech32Sapling::decode::<N>(address)?;
                 let format = ZcashFormat::Sapling(Some(Self::get_diversifier(address)?));
 
                 return Ok(Self {
@@ -212,40 +705,8 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                     format,
                     _network: PhantomData,
                 });
-            } else if &address[0..2] == "zs" && address.len() > 77 {
-                // Check that the network bytes correspond with the correct network.
-                let _ = N::from_address_prefix(&address[0..2].as_bytes().to_vec())?;
-                let format = ZcashFormat::Sapling(Some(Self::get_diversifier(address)?));
-
-                return Ok(Self {
-                    address: address.into(),
-                    format,
-                    _network: PhantomData,
-                });
-            } else if &address[0..2] == "zt" && address.len() == 95 {
-                let data = address.from_base58()?;
-
-                // Check that the network bytes correspond with the correct network.
-                let _ = N::from_address_prefix(&data[0..2].to_vec())?;
-                let format = ZcashFormat::Sprout;
-
-                return Ok(Self {
-                    address: address.into(),
-                    format,
-                    _network: PhantomData,
-                });
-            } else if &address[0..2] == "zc" && address.len() == 95 {
-                let data = address.from_base58()?;
-
-                // Check that the network bytes correspond with the correct network.
-                let _ = N::from_address_prefix(&data[0..2].to_vec())?;
-                let format = ZcashFormat::Sprout;
-
-                return Ok(Self {
-                    address: address.into(),
-                    format,
-                    _network: PhantomData,
-                });
+            } else if (&address[0..2] == "zt" || &address[0..2] == "zc") && address.len() == 95 {
+                return Self::parse_sprout(address);
             }
         }
 
@@ -265,6 +726,18 @@ mod tests {
     use crate::network::*;
     use wagyu_model::public_key::PublicKey;
 
+    /// Returns an endless iterator of random compressed P2PKH private keys whose addresses
+    /// have a payload of the given leading byte, to cover the range of encodings of the decoder.
+    fn keys_with_leading_byte<N: ZcashNetwork, R: Rng>(
+        rng: &mut R,
+        byte: u8,
+    ) -> impl Iterator<Item = ZcashPrivateKey<N>> + '_ {
+        std::iter::repeat_with(move || ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap()).filter(move |private_key| {
+            let address = private_key.to_address(&ZcashFormat::P2PKH).unwrap();
+            Base58P2PKH::decode::<N>(&address.to_string()).unwrap().0[0] == byte
+        })
+    }
+
     fn test_from_private_key<N: ZcashNetwork>(
         expected_address: &str,
         private_key: &ZcashPrivateKey<N>,
@@ -742,6 +1215,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_uri() {
+        type N = Mainnet;
+
+        let address = ZcashAddress::<N>::from_str("t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM").unwrap();
+
+        let uri = address.to_uri(None, None).unwrap();
+        assert_eq!("zcash:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM", uri);
+        assert_eq!(
+            (address.clone(), None, None),
+            ZcashAddress::<N>::from_uri(&uri).unwrap()
+        );
+
+        let uri = address.to_uri(Some(1.25), Some("Thank you & goodbye!")).unwrap();
+        assert_eq!(
+            "zcash:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM?amount=1.25&memo=Thank%20you%20%26%20goodbye%21",
+            uri
+        );
+        assert_eq!(
+            (address.clone(), Some(1.25), Some("Thank you & goodbye!".into())),
+            ZcashAddress::<N>::from_uri(&uri).unwrap()
+        );
+
+        assert!(address.to_uri(Some(-1.0), None).is_err());
+        assert!(address.to_uri(Some(f64::NAN), None).is_err());
+        assert!(ZcashAddress::<N>::from_uri("t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM").is_err());
+        assert!(ZcashAddress::<N>::from_uri("zcash\u{e9}").is_err());
+        assert!(ZcashAddress::<N>::from_uri("zcas\u{e9}:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM").is_err());
+        assert!(ZcashAddress::<N>::from_uri("zcash:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM\u{e9}").is_err());
+        assert!(ZcashAddress::<N>::from_uri("zcash:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM?amount=-2").is_err());
+        assert!(ZcashAddress::<N>::from_uri("zcash:t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM?memo=%2").is_err());
+    }
+
+    #[test]
+    fn test_transparent_and_shielded() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("KxYzZuBPkE3rnEEGCdsB6dCzxN1D4xoY5ogKoxbdUdkxbRzvgbij").unwrap();
+        let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+
+        let address = ZcashAddress::<N>::from_str("t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM").unwrap();
+        assert!(address.is_transparent());
+        assert!(ZcashShieldedAddress::try_from(address.clone()).is_err());
+
+        let transparent = ZcashTransparentAddress::try_from(address.clone()).unwrap();
+        assert_eq!(address, *transparent.as_address());
+        assert_eq!(address.to_string(), transparent.to_string());
+        assert_eq!(
+            hash160(&match public_key {
+                ZcashPublicKey::P2PKH(public_key) => public_key.public_key.serialize().to_vec(),
+                _ => unreachable!(),
+            }),
+            transparent.to_hash().unwrap().to_vec()
+        );
+
+        let address = ZcashAddress::<N>::from_str(
+            "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf",
+        )
+        .unwrap();
+        assert!(address.is_shielded());
+        assert!(ZcashTransparentAddress::try_from(address.clone()).is_err());
+
+        let shielded = ZcashShieldedAddress::try_from(address.clone()).unwrap();
+        assert_eq!(address, ZcashAddress::from(shielded));
+    }
+
     #[test]
     fn test_p2pkh_invalid() {
         type N = Mainnet;
@@ -776,4 +1316,438 @@ mod tests {
         let address = "t1J8w8EMM1Rs26zJFu3Deo6ougWhNhPXUZtt1J8w8EMM1Rs26zJFu3Deo6ougWhNhPXUZt";
         assert!(ZcashAddress::<N>::from_str(address).is_err());
     }
+
+    #[test]
+    fn test_from_hash160() {
+        fn test<N: ZcashNetwork>(private_key: &str) {
+            let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+            let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+            let expected_address = ZcashAddress::<N>::from_public_key(&public_key, &ZcashFormat::P2PKH).unwrap();
+
+            let mut hash = [0u8; 20];
+            hash.copy_from_slice(&hash160(&hex::decode(public_key.to_string()).unwrap()));
+            let address = ZcashAddress::<N>::from_hash160(hash, &ZcashFormat::P2PKH).unwrap();
+            assert_eq!(expected_address, address);
+
+            let address = ZcashAddress::<N>::from_hash160(hash, &ZcashFormat::P2SH).unwrap();
+            assert_eq!(ZcashFormat::P2SH, address.format());
+            assert_eq!(
+                ZcashFormat::P2SH,
+                ZcashAddress::<N>::from_str(&address.to_string()).unwrap().format()
+            );
+
+            assert!(ZcashAddress::<N>::from_hash160(hash, &ZcashFormat::Sprout).is_err());
+            assert!(ZcashAddress::<N>::from_hash160(hash, &ZcashFormat::Sapling(None)).is_err());
+        }
+
+        test::<Mainnet>("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g");
+        test::<Mainnet>("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ");
+        test::<Testnet>("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M");
+    }
+
+    #[test]
+    fn test_p2pkh_with_hash() {
+        type N = Mainnet;
+
+        fn truncate(data: &[u8]) -> Vec<u8> {
+            data[0..20].to_vec()
+        }
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let public_key = match ZcashPublicKey::<N>::from_private_key(&private_key) {
+            ZcashPublicKey::<N>::P2PKH(public_key) => public_key,
+            _ => panic!("expected a p2pkh public key"),
+        };
+
+        let address = ZcashAddress::<N>::p2pkh_with_hash(&public_key, truncate).unwrap();
+        let (payload, _) = Base58P2PKH::decode::<N>(&address.to_string()).unwrap();
+        assert_eq!(&public_key.public_key.serialize()[0..20], &payload[..]);
+
+        // The default hash function is hash160.
+        assert_eq!(
+            ZcashAddress::<N>::p2pkh(&public_key),
+            ZcashAddress::<N>::p2pkh_with_hash(&public_key, hash160).unwrap()
+        );
+
+        // The hash must produce a 20-byte payload.
+        assert!(ZcashAddress::<N>::p2pkh_with_hash(&public_key, |data| data.to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_p2sh() {
+        fn encode<N: ZcashNetwork>(payload: &[u8]) -> String {
+            let mut data = N::to_address_prefix(&ZcashFormat::P2SH);
+            data.extend(payload);
+            let sum = checksum(&data)[0..4].to_vec();
+            data.extend(sum);
+            data.to_base58()
+        }
+
+        let address = encode::<Mainnet>(&[0x5c; 20]);
+        assert!(address.starts_with("t3"));
+        assert!(ZcashAddress::<Mainnet>::is_valid_p2sh(&address));
+        assert!(!ZcashAddress::<Testnet>::is_valid_p2sh(&address));
+        assert!(ZcashAddress::<Testnet>::is_valid_p2sh(&encode::<Testnet>(&[0x5c; 20])));
+
+        // Short redeem script hash
+        let address = encode::<Mainnet>(&[0x5c; 19]);
+        assert!(!ZcashAddress::<Mainnet>::is_valid_p2sh(&address));
+        assert!(ZcashAddress::<Mainnet>::from_str(&address).is_err());
+
+        // P2PKH address
+        assert!(!ZcashAddress::<Mainnet>::is_valid_p2sh(
+            "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp"
+        ));
+    }
+
+    #[test]
+    fn test_to_short() {
+        let address = ZcashAddress::<Mainnet>::from_str("t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp").unwrap();
+        assert_eq!("t1Rxy...zQTKp", address.to_short(5, 5).unwrap());
+        assert_eq!("t1...", address.to_short(2, 0).unwrap());
+        assert_eq!("...TKp", address.to_short(0, 3).unwrap());
+        assert_eq!(address.to_string(), address.to_short(20, 15).unwrap());
+
+        assert!(address.to_short(20, 16).is_err());
+        assert!(address.to_short(36, 0).is_err());
+        assert!(address.to_short(0, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn parse_sprout() {
+        type N = Mainnet;
+
+        let address = "zcJLC7a3aRJohMNCVjSZQ8jFuofhAHJNAY4aX5soDkYfgNejzKnEZbucJmVibLWCwK8dyyfDhNhf3foXDDTouweC382LcX5";
+        let sprout = ZcashAddress::<N>::parse_sprout(address).unwrap();
+        assert_eq!(address, sprout.to_string());
+        assert_eq!(ZcashFormat::Sprout, sprout.format());
+        assert_eq!(sprout, ZcashAddress::<N>::from_str(address).unwrap());
+
+        // Invalid checksum
+        let tampered = address.replace("LcX5", "LcX6");
+        assert!(ZcashAddress::<N>::parse_sprout(&tampered).is_err());
+        assert!(ZcashAddress::<N>::from_str(&tampered).is_err());
+
+        // Mismatched network
+        assert!(ZcashAddress::<Testnet>::parse_sprout(address).is_err());
+
+        // Non-Sprout addresses
+        assert!(ZcashAddress::<N>::parse_sprout("t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp").is_err());
+        assert!(ZcashAddress::<N>::parse_sprout(
+            "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn collision_probability() {
+        assert_eq!(0.0, super::collision_probability(0));
+        assert_eq!(0.0, super::collision_probability(1));
+        assert!(super::collision_probability(1_000_000) < 1e-30);
+
+        let probabilities: Vec<f64> = [2, 1 << 20, 1 << 40, 1 << 60, 1 << 63, u64::MAX]
+            .iter()
+            .map(|n| super::collision_probability(*n))
+            .collect();
+        probabilities.windows(2).for_each(|pair| assert!(pair[0] < pair[1]));
+        assert!(probabilities.iter().all(|p| *p > 0.0 && *p <= 1.0));
+    }
+
+    #[test]
+    fn validate_addresses() {
+        let addresses = [
+            "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+            "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2poe",
+            " zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf ",
+            "tmWT3bvWCHQkAXXucPjWHqLs9EyWUDdzSuN",
+            "",
+        ];
+        let results = super::validate_addresses::<Mainnet>(&addresses);
+        assert_eq!(addresses.len(), results.len());
+
+        let valid: Vec<bool> = results.iter().map(Result::is_ok).collect();
+        assert_eq!(vec![true, false, true, false, false], valid);
+        assert_eq!(addresses[0], results[0].as_ref().unwrap().to_string());
+        assert_eq!(addresses[2].trim(), results[2].as_ref().unwrap().to_string());
+        assert!(super::validate_addresses::<Mainnet>(&[]).is_empty());
+    }
+
+    #[test]
+    fn wif_to_address() {
+        let compressed = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let uncompressed = "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ";
+        let testnet = "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M";
+
+        assert_eq!(
+            "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+            super::wif_to_address::<Mainnet>(compressed, &ZcashFormat::P2PKH)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp",
+            super::wif_to_address::<Mainnet>(uncompressed, &ZcashFormat::P2PKH)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "tmWT3bvWCHQkAXXucPjWHqLs9EyWUDdzSuN",
+            super::wif_to_address::<Testnet>(testnet, &ZcashFormat::P2PKH)
+                .unwrap()
+                .to_string()
+        );
+        assert!(super::wif_to_address::<Mainnet>(testnet, &ZcashFormat::P2PKH).is_err());
+        assert!(super::wif_to_address::<Testnet>(compressed, &ZcashFormat::P2PKH).is_err());
+    }
+
+    #[test]
+    fn verify_pairs() {
+        let pairs: Vec<(String, String)> = [
+            (
+                "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+                "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+            ),
+            (
+                "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+                "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp",
+            ),
+            (
+                "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+                "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp",
+            ),
+            (
+                "SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut",
+                "zcJLC7a3aRJohMNCVjSZQ8jFuofhAHJNAY4aX5soDkYfgNejzKnEZbucJmVibLWCwK8dyyfDhNhf3foXDDTouweC382LcX5",
+            ),
+            (
+                "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4h",
+                "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+            ),
+        ]
+        .iter()
+        .map(|(wif, address)| (wif.to_string(), address.to_string()))
+        .collect();
+
+        assert_eq!(
+            vec![true, false, true, true, false],
+            super::verify_pairs::<Mainnet>(&pairs)
+        );
+        assert_eq!(
+            vec![false, false, false, false, false],
+            super::verify_pairs::<Testnet>(&pairs)
+        );
+    }
+
+    #[test]
+    fn assert_unique_addresses() {
+        type N = Mainnet;
+
+        let private_keys: Vec<ZcashPrivateKey<N>> = [
+            "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+            "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+            "SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut",
+        ]
+        .iter()
+        .map(|wif| ZcashPrivateKey::<N>::from_str(wif).unwrap())
+        .collect();
+        assert!(super::assert_unique_addresses(&private_keys, Uniqueness::Address).is_ok());
+        assert!(super::assert_unique_addresses(&private_keys, Uniqueness::Secret).is_ok());
+
+        // The compressed and uncompressed WIFs of the same secret key have different addresses.
+        let compressed =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let uncompressed = match &compressed {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+                spending_key.to_secp256k1_secret_key(),
+                false,
+            )),
+            _ => unreachable!(),
+        };
+        let variants = [private_keys[1].clone(), compressed.clone(), uncompressed];
+        assert!(super::assert_unique_addresses(&variants, Uniqueness::Address).is_ok());
+        assert_eq!(
+            "duplicate private keys at indices 1 and 2",
+            super::assert_unique_addresses(&variants, Uniqueness::Secret)
+                .unwrap_err()
+                .to_string()
+        );
+
+        let repeated = [compressed.clone(), compressed];
+        assert!(super::assert_unique_addresses(&repeated, Uniqueness::Address).is_err());
+        assert!(super::assert_unique_addresses::<N>(&[], Uniqueness::Secret).is_ok());
+    }
+
+    #[test]
+    fn p2sh_cltv() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+        let public_key_bytes = hex::decode(public_key.to_string()).unwrap();
+
+        // 500,000 is 0x07a120, encoded as the 3-byte script number 20 a1 07
+        let script = ZcashAddress::<N>::cltv_redeem_script(&public_key, 500_000).unwrap();
+        assert_eq!(
+            &[0x03, 0x20, 0xa1, 0x07, OP_CHECKLOCKTIMEVERIFY, OP_DROP, 0x21],
+            &script[0..7]
+        );
+        assert_eq!(&public_key_bytes[..], &script[7..40]);
+        assert_eq!(&[OP_CHECKSIG], &script[40..]);
+
+        // A locktime with the sign bit set is padded with a zero byte
+        let script = ZcashAddress::<N>::cltv_redeem_script(&public_key, 0x80).unwrap();
+        assert_eq!(&[0x02, 0x80, 0x00, OP_CHECKLOCKTIMEVERIFY], &script[0..4]);
+        let script = ZcashAddress::<N>::cltv_redeem_script(&public_key, u32::MAX).unwrap();
+        assert_eq!(
+            &[0x05, 0xff, 0xff, 0xff, 0xff, 0x00, OP_CHECKLOCKTIMEVERIFY],
+            &script[0..7]
+        );
+
+        let address = ZcashAddress::<N>::p2sh_cltv(&public_key, 500_000).unwrap();
+        assert_eq!(ZcashFormat::P2SH, address.format());
+        assert!(address.to_string().starts_with("t3"));
+        assert_eq!(address, ZcashAddress::<N>::from_str(&address.to_string()).unwrap());
+        assert_ne!(address, ZcashAddress::<N>::p2sh_cltv(&public_key, 500_001).unwrap());
+
+        assert!(ZcashAddress::<N>::p2sh_cltv(&public_key, 0).is_err());
+        let sapling = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(ZcashAddress::<N>::p2sh_cltv(&ZcashPublicKey::<N>::from_private_key(&sapling), 500_000).is_err());
+    }
+
+    #[test]
+    fn from_minikey() {
+        let minikey = "S6c56bnXQiBjk9mqSYE7ykVQ7NzrRy";
+        assert_eq!(
+            "t1VVKfCsA1WAKQRMYUHR5uYi3EbronhzUiK",
+            ZcashAddress::<Mainnet>::from_minikey(minikey, &ZcashFormat::P2PKH)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "tmML4z3MZQAfpYfYz91ipmDNnqawdGwBH6n",
+            ZcashAddress::<Testnet>::from_minikey(minikey, &ZcashFormat::P2PKH)
+                .unwrap()
+                .to_string()
+        );
+        assert!(ZcashAddress::<Mainnet>::from_minikey("S6c56bnXQiBjk9mqSYE7ykVQ7NzrRz", &ZcashFormat::P2PKH).is_err());
+    }
+
+    #[test]
+    fn test_from_str_leading_bytes() {
+        fn test_leading_bytes<N: ZcashNetwork>() {
+            let rng = &mut StdRng::seed_from_u64(0);
+            [0x00, 0x01, 0x7f, 0x80, 0xff].iter().for_each(|byte| {
+                keys_with_leading_byte::<N, _>(rng, *byte)
+                    .take(3)
+                    .for_each(|private_key| {
+                        let expected = private_key.to_address(&ZcashFormat::P2PKH).unwrap();
+                        let address = ZcashAddress::<N>::from_str(&expected.to_string()).unwrap();
+                        assert_eq!(expected, address);
+                        assert_eq!(*byte, Base58P2PKH::decode::<N>(&address.to_string()).unwrap().0[0]);
+                    });
+            });
+        }
+
+        test_leading_bytes::<Mainnet>();
+        test_leading_bytes::<Testnet>();
+    }
+
+    #[test]
+    fn test_is_p2pkh_and_is_p2sh() {
+        type N = Mainnet;
+
+        let address = ZcashAddress::<N>::from_str("t1MoMR1XdnPqLBWf5XkchWTkGNrveYLCaiM").unwrap();
+        assert!(address.is_p2pkh());
+        assert!(!address.is_p2sh());
+
+        let address = ZcashAddress::<N>::from_str("t3Vz22vK5z2LcKEdg16Yv4FFneEL1zg9ojd").unwrap();
+        assert!(address.is_p2sh());
+        assert!(!address.is_p2pkh());
+
+        let address = ZcashAddress::<N>::from_str(
+            "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf",
+        )
+        .unwrap();
+        assert!(!address.is_p2pkh());
+        assert!(!address.is_p2sh());
+    }
+
+    #[test]
+    fn test_from_identity() {
+        type N = Mainnet;
+
+        let address = ZcashAddress::<N>::from_identity("satoshi@example.com", "wagyu").unwrap();
+        assert_eq!("t1VcuF6UKK9eaejsNqqyo4K7XwpwgzUCWet", address.to_string());
+        assert_eq!(ZcashFormat::P2PKH, address.format());
+        assert_eq!(
+            address,
+            ZcashAddress::<N>::from_identity("satoshi@example.com", "wagyu").unwrap()
+        );
+
+        assert_ne!(
+            address,
+            ZcashAddress::<N>::from_identity("satoshi@example.com", "wagyu-cli").unwrap()
+        );
+        assert_ne!(
+            address,
+            ZcashAddress::<N>::from_identity("satoshi@example.co", "mwagyu").unwrap()
+        );
+        assert!(ZcashAddress::<Testnet>::from_identity("satoshi@example.com", "wagyu")
+            .unwrap()
+            .to_string()
+            .starts_with("tm"));
+    }
+
+    #[test]
+    fn test_from_str_conformance() {
+        use crate::private_key::CompressionPolicy;
+
+        /// Asserts the address parses from its encoding to an equal address of the same encoding.
+        fn test_round_trip<N: ZcashNetwork>(address: &ZcashAddress<N>) {
+            let parsed = ZcashAddress::<N>::from_str(&address.to_string()).unwrap();
+            assert_eq!(address.to_string(), parsed.to_string());
+            assert_eq!(parsed, ZcashAddress::<N>::from_str(&parsed.to_string()).unwrap());
+            match address.format() {
+                ZcashFormat::Sapling(_) => assert!(parsed.format().is_shielded()),
+                format => assert_eq!(format, parsed.format()),
+            }
+        }
+
+        fn test_conformance<N: ZcashNetwork>() {
+            let rng = &mut StdRng::seed_from_u64(0);
+            (0..8).for_each(|locktime| {
+                let compressed = ZcashPrivateKey::<N>::new_with_policy(rng, CompressionPolicy::Compressed).unwrap();
+                let uncompressed = ZcashPrivateKey::<N>::new_with_policy(rng, CompressionPolicy::Uncompressed).unwrap();
+                let sprout = ZcashPrivateKey::<N>::new_sprout(rng).unwrap();
+                let sapling = ZcashPrivateKey::<N>::new_sapling(rng).unwrap();
+
+                test_round_trip(&compressed.to_address(&ZcashFormat::P2PKH).unwrap());
+                test_round_trip(&uncompressed.to_address(&ZcashFormat::P2PKH).unwrap());
+                test_round_trip(&ZcashAddress::<N>::p2sh_cltv(&compressed.to_public_key(), locktime + 1).unwrap());
+                test_round_trip(&sprout.to_address(&ZcashFormat::Sprout).unwrap());
+                test_round_trip(&sapling.to_address(&ZcashFormat::Sapling(None)).unwrap());
+            });
+
+            // Payloads with leading zero bytes encode to leading '1' characters after the prefix in base58.
+            let mut payloads = vec![[0u8; 20], [0xff; 20]];
+            let mut payload = [0u8; 20];
+            payload[19] = 1;
+            payloads.push(payload);
+            payload[2] = 0x80;
+            payloads.push(payload);
+            payloads.iter().for_each(|payload| {
+                test_round_trip(&ZcashAddress::<N>::from_payload(payload, &ZcashFormat::P2PKH).unwrap());
+                test_round_trip(&ZcashAddress::<N>::from_payload(payload, &ZcashFormat::P2SH).unwrap());
+            });
+        }
+
+        test_conformance::<Mainnet>();
+        test_conformance::<Testnet>();
+        test_conformance::<Regtest>();
+    }
 }
@@ -0,0 +1,148 @@
+use crate::address::ZcashAddress;
+use crate::network::ZcashNetwork;
+use wagyu_model::AddressError;
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+    str::FromStr,
+};
+
+/// Represents a book of Zcash addresses, each identified by a unique label
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressBook<N: ZcashNetwork> {
+    /// The addresses of the book, ordered by label
+    entries: BTreeMap<String, ZcashAddress<N>>,
+}
+
+/// The serialized form of an address book entry
+#[derive(Serialize, Deserialize)]
+struct AddressBookEntry {
+    label: String,
+    address: String,
+}
+
+impl<N: ZcashNetwork> AddressBook<N> {
+    /// Returns a new empty address book.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the address under the given label, returning an error if the label is already in use.
+    pub fn add(&mut self, label: &str, address: ZcashAddress<N>) -> Result<(), AddressError> {
+        if self.entries.contains_key(label) {
+            return Err(AddressError::Message(format!(
+                "duplicate address book label: {}",
+                label
+            )));
+        }
+        self.entries.insert(label.into(), address);
+        Ok(())
+    }
+
+    /// Returns the address of the given label, if any.
+    pub fn get(&self, label: &str) -> Option<&ZcashAddress<N>> {
+        self.entries.get(label)
+    }
+
+    /// Returns the number of addresses in the book.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the book has no addresses.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the address book read from the given reader, as a JSON array of `label` and `address`
+    /// objects, verifying that each address is valid and each label is unique.
+    pub fn import<R: Read>(reader: R) -> Result<Self, AddressError> {
+        let entries: Vec<AddressBookEntry> = serde_json::from_reader(reader)
+            .map_err(|error| AddressError::Crate("serde_json", format!("{:?}", error)))?;
+
+        let mut book = Self::new();
+        for entry in entries {
+            book.add(&entry.label, ZcashAddress::<N>::from_str(&entry.address)?)?;
+        }
+        Ok(book)
+    }
+
+    /// Writes the address book to the given writer as a JSON array of `label` and `address` objects,
+    /// ordered by label.
+    pub fn export<W: Write>(&self, writer: W) -> Result<(), AddressError> {
+        let entries: Vec<AddressBookEntry> = self
+            .entries
+            .iter()
+            .map(|(label, address)| AddressBookEntry {
+                label: label.clone(),
+                address: address.to_string(),
+            })
+            .collect();
+
+        serde_json::to_writer_pretty(writer, &entries)
+            .map_err(|error| AddressError::Crate("serde_json", format!("{:?}", error)))
+    }
+}
+
+impl<N: ZcashNetwork> Default for AddressBook<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    #[test]
+    fn import_export() {
+        type N = Mainnet;
+
+        let file = r#"[
+            { "label": "savings", "address": "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod" },
+            { "label": "shielded", "address": "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf" }
+        ]"#;
+        let mut book = AddressBook::<N>::import(file.as_bytes()).unwrap();
+        assert_eq!(2, book.len());
+        assert_eq!(
+            "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+            book.get("savings").unwrap().to_string()
+        );
+
+        // Duplicate label
+        let address = ZcashAddress::<N>::from_str("t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp").unwrap();
+        assert!(book.add("savings", address.clone()).is_err());
+        assert_eq!(2, book.len());
+        book.add("spending", address).unwrap();
+
+        let mut exported = vec![];
+        book.export(&mut exported).unwrap();
+        let exported = String::from_utf8(exported).unwrap();
+        assert!(exported.find("savings").unwrap() < exported.find("spending").unwrap());
+        assert_eq!(book, AddressBook::<N>::import(exported.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn import_invalid() {
+        type N = Mainnet;
+
+        // Duplicate label
+        let file = r#"[
+            { "label": "savings", "address": "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod" },
+            { "label": "savings", "address": "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp" }
+        ]"#;
+        assert!(AddressBook::<N>::import(file.as_bytes()).is_err());
+
+        // Mismatched network
+        let file = r#"[{ "label": "savings", "address": "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod" }]"#;
+        assert!(AddressBook::<Testnet>::import(file.as_bytes()).is_err());
+
+        // Invalid JSON
+        assert!(AddressBook::<N>::import(&file.as_bytes()[1..]).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use wagyu_model::{crypto::checksum, PrivateKeyError};
+
+use std::str::{self, FromStr};
+
+const ARMOR_BEGIN: &str = "-----BEGIN WAGYU KEY-----";
+const ARMOR_END: &str = "-----END WAGYU KEY-----";
+
+/// The number of base64 characters per armored line
+const ARMOR_LINE_LENGTH: usize = 64;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl<N: ZcashNetwork> ZcashPrivateKey<N> {
+    /// Returns the private key as an armored text block, which encodes the network and
+    /// encoded private key separated by a NUL byte, followed by a 4-byte checksum, in base64.
+    pub fn to_armor(&self) -> String {
+        let mut payload = format!("{}\0{}", N::NAME, self).into_bytes();
+        let sum = checksum(&payload)[0..4].to_vec();
+        payload.extend(sum);
+
+        let encoded = encode_base64(&payload);
+        let mut armor = vec![ARMOR_BEGIN];
+        armor.extend(
+            encoded
+                .as_bytes()
+                .chunks(ARMOR_LINE_LENGTH)
+                .map(|line| str::from_utf8(line).unwrap_or_default()),
+        );
+        armor.push(ARMOR_END);
+        armor.join("\n") + "\n"
+    }
+
+    /// Returns the private key of a given armored text block, verifying its checksum and network.
+    pub fn from_armor(armor: &str) -> Result<Self, PrivateKeyError> {
+        let lines: Vec<&str> = armor.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        if lines.len() < 3 || lines[0] != ARMOR_BEGIN || lines[lines.len() - 1] != ARMOR_END {
+            return Err(PrivateKeyError::Message("invalid armor header or footer".into()));
+        }
+
+        let data = decode_base64(&lines[1..lines.len() - 1].concat())?;
+        if data.len() < 5 {
+            return Err(PrivateKeyError::InvalidByteLength(data.len()));
+        }
+
+        let (payload, sum) = data.split_at(data.len() - 4);
+        let mut actual = [0u8; 4];
+        actual.copy_from_slice(sum);
+        let mut expected = [0u8; 4];
+        expected.copy_from_slice(&checksum(payload)[0..4]);
+        if actual != expected {
+            return Err(PrivateKeyError::InvalidChecksum { expected, actual });
+        }
+
+        let payload = str::from_utf8(payload).map_err(|error| PrivateKeyError::Message(error.to_string()))?;
+        let mut fields = payload.splitn(2, '\0');
+        match (fields.next(), fields.next()) {
+            (Some(network), Some(private_key)) if network == N::NAME => Self::from_str(private_key),
+            (Some(network), Some(_)) => Err(PrivateKeyError::InvalidNetwork(N::NAME.into(), network.into())),
+            _ => Err(PrivateKeyError::Message("invalid armor payload".into())),
+        }
+    }
+}
+
+/// Returns the padded base64 encoding of the given bytes.
+fn encode_base64(data: &[u8]) -> String {
+    data.chunks(3)
+        .flat_map(|chunk| {
+            let block = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |block, (i, byte)| block | u32::from(*byte) << (16 - 8 * i));
+            (0..4).map(move |i| match i <= chunk.len() {
+                true => char::from(BASE64_ALPHABET[(block >> (18 - 6 * i) & 0x3f) as usize]),
+                false => '=',
+            })
+        })
+        .collect()
+}
+
+/// Returns the bytes of the given padded base64 encoding.
+fn decode_base64(encoded: &str) -> Result<Vec<u8>, PrivateKeyError> {
+    let encoded = encoded.as_bytes();
+    let chunks = encoded.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err(PrivateKeyError::InvalidCharacterLength(encoded.len()));
+    }
+
+    let count = chunks.len();
+    let mut data = Vec::with_capacity(count * 3);
+    for (index, chunk) in chunks.enumerate() {
+        let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+        if padding > 2 || (padding > 0 && index != count - 1) {
+            return Err(PrivateKeyError::Message("invalid base64 padding".into()));
+        }
+
+        let mut block = 0u32;
+        for (i, c) in chunk[..4 - padding].iter().enumerate() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(|| PrivateKeyError::Message(format!("invalid base64 character: {:?}", char::from(*c))))?;
+            block |= (value as u32) << (18 - 6 * i);
+        }
+        data.extend(block.to_be_bytes()[1..4 - padding].iter());
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_round_trip<N: ZcashNetwork>(private_key: &str) {
+        let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+        let armor = private_key.to_armor();
+        assert!(armor.starts_with(ARMOR_BEGIN));
+        assert!(armor.trim_end().ends_with(ARMOR_END));
+        assert!(armor.lines().all(|line| line.len() <= ARMOR_LINE_LENGTH));
+        assert_eq!(private_key, ZcashPrivateKey::<N>::from_armor(&armor).unwrap());
+    }
+
+    #[test]
+    fn round_trip() {
+        test_round_trip::<Mainnet>("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g");
+        test_round_trip::<Mainnet>("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ");
+        test_round_trip::<Mainnet>("SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut");
+        test_round_trip::<Testnet>("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M");
+        test_round_trip::<Testnet>(
+            "secret-spending-key-test1fygsm6l3ltqqs63040tq426p85pgzuetdeg6q0wka38nx3rfa70sa9qp0v",
+        );
+    }
+
+    #[test]
+    fn from_armor_invalid() {
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let armor = private_key.to_armor();
+
+        // Mismatched network
+        assert!(ZcashPrivateKey::<Testnet>::from_armor(&armor).is_err());
+
+        // Missing header
+        assert!(ZcashPrivateKey::<Mainnet>::from_armor(&armor.replacen(ARMOR_BEGIN, "", 1)).is_err());
+
+        // Invalid checksum
+        let lines: Vec<&str> = armor.lines().collect();
+        let mut corrupted = lines[1].to_string();
+        corrupted.replace_range(0..1, if &corrupted[0..1] == "A" { "B" } else { "A" });
+        let tampered = armor.replacen(lines[1], &corrupted, 1);
+        assert!(ZcashPrivateKey::<Mainnet>::from_armor(&tampered).is_err());
+    }
+
+    #[test]
+    fn base64() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        vectors.iter().for_each(|(data, encoded)| {
+            assert_eq!(*encoded, encode_base64(data.as_bytes()));
+            assert_eq!(data.as_bytes(), &decode_base64(encoded).unwrap()[..]);
+        });
+
+        assert!(decode_base64("Zm9").is_err());
+        assert!(decode_base64("Zm=v").is_err());
+        assert!(decode_base64("Zg==Zm9v").is_err());
+        assert!(decode_base64("Zm9*").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use crate::network::ZcashNetwork;
+use crate::private_key::{CompressionPolicy, ZcashPrivateKey};
+use wagyu_model::{PrivateKey, PrivateKeyError};
+
+use rand::Rng;
+use std::io::Write;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Mutex, OnceLock},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+/// Returns `count` randomly-generated private keys of network `N`.
+pub fn generate_batch<N: ZcashNetwork, R: Rng>(
+    rng: &mut R,
+    count: usize,
+) -> Result<Vec<ZcashPrivateKey<N>>, PrivateKeyError> {
+    (0..count).map(|_| ZcashPrivateKey::<N>::new(rng)).collect()
+}
+
+/// Writes `count` randomly-generated private keys of network `N` to the writer, one WIF per line.
+/// Each key is written as it is generated, so the memory used does not grow with the count.
+pub fn stream_generate<N: ZcashNetwork, R: Rng, W: Write>(
+    rng: &mut R,
+    compressed: bool,
+    count: usize,
+    writer: &mut W,
+) -> Result<(), PrivateKeyError> {
+    let policy = match compressed {
+        true => CompressionPolicy::Compressed,
+        false => CompressionPolicy::Uncompressed,
+    };
+    for _ in 0..count {
+        writeln!(writer, "{}", ZcashPrivateKey::<N>::new_with_policy(rng, policy)?)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Returns a future of `count` randomly-generated private keys of network `N`.
+/// The keys are generated on a bounded pool of threads, so awaiting the future does not block the executor,
+/// and a panic while generating them resolves the future with an error.
+#[cfg(feature = "async")]
+pub fn generate_batch_async<N: ZcashNetwork>(count: usize) -> GenerateBatch<N> {
+    spawn_batch(move || generate_batch(&mut rand::thread_rng(), count))
+}
+
+/// The number of threads generating batches of private keys for `generate_batch_async`
+#[cfg(feature = "async")]
+const BATCH_POOL_THREADS: usize = 4;
+
+/// A job run by a thread of the batch generation pool
+#[cfg(feature = "async")]
+type BatchJob = Box<dyn FnOnce() + Send>;
+
+/// Returns the sender of jobs to the batch generation pool, starting its threads on first use.
+#[cfg(feature = "async")]
+fn batch_pool() -> &'static Mutex<mpsc::Sender<BatchJob>> {
+    static POOL: OnceLock<Mutex<mpsc::Sender<BatchJob>>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<BatchJob>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..BATCH_POOL_THREADS {
+            let receiver = receiver.clone();
+            thread::spawn(move || loop {
+                // The receiver is unlocked before the job runs, so the threads run jobs concurrently.
+                let job = receiver.lock().expect("batch generation pool is poisoned").recv();
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => return,
+                }
+            });
+        }
+        Mutex::new(sender)
+    })
+}
+
+/// Returns a future of the result of the given batch generation, run on the batch generation pool.
+/// A panic in the generation is caught, so the pool thread survives and the future resolves with an error.
+#[cfg(feature = "async")]
+fn spawn_batch<N: ZcashNetwork, F>(generate: F) -> GenerateBatch<N>
+where
+    F: FnOnce() -> Result<Vec<ZcashPrivateKey<N>>, PrivateKeyError> + Send + 'static,
+{
+    let state = Arc::new(Mutex::new(GenerateBatchState {
+        result: None,
+        waker: None,
+    }));
+
+    let worker = state.clone();
+    let job: BatchJob = Box::new(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(generate))
+            .unwrap_or_else(|_| Err(PrivateKeyError::Message("batch generation panicked".into())));
+        let mut state = worker.lock().expect("batch generation state is poisoned");
+        state.result = Some(result);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    });
+
+    if batch_pool()
+        .lock()
+        .expect("batch generation pool is poisoned")
+        .send(job)
+        .is_err()
+    {
+        let mut state = state.lock().expect("batch generation state is poisoned");
+        state.result = Some(Err(PrivateKeyError::Message("batch generation pool is closed".into())));
+    }
+
+    GenerateBatch { state }
+}
+
+/// Represents the future of a batch of private keys generated by `generate_batch_async`
+#[cfg(feature = "async")]
+pub struct GenerateBatch<N: ZcashNetwork> {
+    state: Arc<Mutex<GenerateBatchState<N>>>,
+}
+
+/// The state shared by a future of a batch of private keys and the pool thread generating them
+#[cfg(feature = "async")]
+struct GenerateBatchState<N: ZcashNetwork> {
+    /// The generated private keys, once the thread has finished
+    result: Option<Result<Vec<ZcashPrivateKey<N>>, PrivateKeyError>>,
+    /// The waker of the task most recently polling the future
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl<N: ZcashNetwork> Future for GenerateBatch<N> {
+    type Output = Result<Vec<ZcashPrivateKey<N>>, PrivateKeyError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut state = self.state.lock().expect("batch generation state is poisoned");
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    #[test]
+    fn test_generate_batch() {
+        let rng = &mut rand::thread_rng();
+        let private_keys = generate_batch::<Mainnet, _>(rng, 3).unwrap();
+        assert_eq!(3, private_keys.len());
+        assert_ne!(private_keys[0], private_keys[1]);
+        assert!(generate_batch::<Mainnet, _>(rng, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stream_generate() {
+        let rng = &mut rand::thread_rng();
+        let mut buffer = Vec::new();
+        stream_generate::<Mainnet, _, _>(rng, true, 1000, &mut buffer).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(1000, lines.len());
+        lines.iter().for_each(|wif| {
+            let (_, _, compressed) = ZcashPrivateKey::<Mainnet>::decode_wif_parts(wif).unwrap();
+            assert!(compressed);
+        });
+
+        let mut buffer = Vec::new();
+        stream_generate::<Testnet, _, _>(rng, false, 3, &mut buffer).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(3, output.lines().count());
+        assert!(output.lines().all(|wif| wif.starts_with('9')));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_generate_batch_async() {
+        use std::task::Wake;
+
+        /// Wakes a task by unparking the thread blocked on it.
+        struct ThreadWaker(thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        /// Returns the output of the given future, parking the current thread while it is pending.
+        fn block_on<F: Future>(future: F) -> F::Output {
+            let mut future = Box::pin(future);
+            let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            loop {
+                match future.as_mut().poll(&mut cx) {
+                    Poll::Ready(output) => return output,
+                    Poll::Pending => thread::park(),
+                }
+            }
+        }
+
+        let private_keys = block_on(generate_batch_async::<Testnet>(3)).unwrap();
+        assert_eq!(3, private_keys.len());
+        assert_ne!(private_keys[0], private_keys[1]);
+        assert!(block_on(generate_batch_async::<Testnet>(0)).unwrap().is_empty());
+
+        // More batches than pool threads are all generated.
+        let batches: Vec<_> = (0..2 * BATCH_POOL_THREADS)
+            .map(|_| generate_batch_async::<Testnet>(2))
+            .collect();
+        batches
+            .into_iter()
+            .for_each(|batch| assert_eq!(2, block_on(batch).unwrap().len()));
+
+        // A panic while generating resolves the future with an error, and the pool keeps working.
+        let panicked = (0..BATCH_POOL_THREADS)
+            .map(|_| spawn_batch::<Testnet, _>(|| panic!("batch generation failed")))
+            .collect::<Vec<_>>();
+        panicked.into_iter().for_each(|batch| {
+            assert!(matches!(block_on(batch), Err(PrivateKeyError::Message(_))));
+        });
+        assert_eq!(3, block_on(generate_batch_async::<Testnet>(3)).unwrap().len());
+    }
+}
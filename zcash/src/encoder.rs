@@ -0,0 +1,310 @@
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use wagyu_model::{crypto::checksum, AddressError};
+
+use base58::{FromBase58, ToBase58};
+use bech32::{Bech32, FromBase32, ToBase32};
+use std::str::{self, FromStr};
+
+/// The interface for encoding and decoding the payload of a Zcash address format.
+pub trait AddressEncoder {
+    /// Returns the address of the given payload on network `N`.
+    fn encode<N: ZcashNetwork>(payload: &[u8]) -> Result<String, AddressError>;
+
+    /// Returns the payload and network of the given address.
+    fn decode<N: ZcashNetwork>(address: &str) -> Result<(Vec<u8>, N), AddressError>;
+}
+
+/// Encodes a 20-byte public key hash as a Base58Check P2PKH address
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Base58P2PKH;
+
+impl AddressEncoder for Base58P2PKH {
+    /// Returns the P2PKH address of the given public key hash.
+    fn encode<N: ZcashNetwork>(payload: &[u8]) -> Result<String, AddressError> {
+        encode_base58::<N>(&ZcashFormat::P2PKH, payload)
+    }
+
+    /// Returns the public key hash and network of the given P2PKH address.
+    fn decode<N: ZcashNetwork>(address: &str) -> Result<(Vec<u8>, N), AddressError> {
+        decode_base58::<N>(&ZcashFormat::P2PKH, address)
+    }
+}
+
+/// Encodes a 20-byte script hash as a Base58Check P2SH address
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Base58P2SH;
+
+impl AddressEncoder for Base58P2SH {
+    /// Returns the P2SH address of the given script hash.
+    fn encode<N: ZcashNetwork>(payload: &[u8]) -> Result<String, AddressError> {
+        encode_base58::<N>(&ZcashFormat::P2SH, payload)
+    }
+
+    /// Returns the script hash and network of the given P2SH address.
+    fn decode<N: ZcashNetwork>(address: &str) -> Result<(Vec<u8>, N), AddressError> {
+        decode_base58::<N>(&ZcashFormat::P2SH, address)
+    }
+}
+
+/// Encodes a 43-byte diversifier and transmission key as a Bech32 Sapling address
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bech32Sapling;
+
+impl AddressEncoder for Bech32Sapling {
+    /// Returns the Sapling address of the given diversifier and transmission key.
+    fn encode<N: ZcashNetwork>(payload: &[u8]) -> Result<String, AddressError> {
+        if payload.len() != 43 {
+            return Err(AddressError::InvalidByteLength(payload.len()));
+        }
+
+        let prefix = N::to_address_prefix(&ZcashFormat::Sapling(None));
+        Ok(Bech32::new(String::from(str::from_utf8(&prefix)?), payload.to_base32())?.to_string())
+    }
+
+    /// Returns the diversifier and transmission key and network of the given Sapling address.
+    fn decode<N: ZcashNetwork>(address: &str) -> Result<(Vec<u8>, N), AddressError> {
+        let bech32 = Bech32::from_str(address)?;
+        let prefix = N::to_address_prefix(&ZcashFormat::Sapling(None));
+        if bech32.hrp().as_bytes() != &prefix[..] {
+            return Err(AddressError::InvalidPrefix(bech32.hrp().as_bytes().to_vec()));
+        }
+
+        let payload: Vec<u8> = FromBase32::from_base32(bech32.data())?;
+        if payload.len() != 43 {
+            return Err(AddressError::InvalidByteLength(payload.len()));
+        }
+
+        Ok((payload, N::from_address_prefix(&prefix)?))
+    }
+}
+
+/// The interface for a Base58 implementation, so that implementations may be swapped in for comparison.
+pub trait Base58Codec {
+    /// Returns the Base58 encoding of the given bytes.
+    fn encode(data: &[u8]) -> String;
+
+    /// Returns the bytes of the given Base58 string.
+    fn decode(data: &str) -> Result<Vec<u8>, AddressError>;
+}
+
+/// The default Base58 implementation of the `base58` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CrateBase58;
+
+impl Base58Codec for CrateBase58 {
+    /// Returns the Base58 encoding of the given bytes.
+    fn encode(data: &[u8]) -> String {
+        data.to_base58()
+    }
+
+    /// Returns the bytes of the given Base58 string.
+    fn decode(data: &str) -> Result<Vec<u8>, AddressError> {
+        Ok(data.from_base58()?)
+    }
+}
+
+/// The Bitcoin Base58 alphabet
+pub(crate) const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// A reference Base58 implementation by repeated division, with no dependencies
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReferenceBase58;
+
+impl Base58Codec for ReferenceBase58 {
+    /// Returns the Base58 encoding of the given bytes.
+    fn encode(data: &[u8]) -> String {
+        // The digits are stored least significant first.
+        let mut digits: Vec<u8> = vec![];
+        for byte in data {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        let zeros = data.iter().take_while(|byte| **byte == 0).count();
+        (0..zeros)
+            .map(|_| BASE58_ALPHABET[0])
+            .chain(digits.iter().rev().map(|digit| BASE58_ALPHABET[*digit as usize]))
+            .map(char::from)
+            .collect()
+    }
+
+    /// Returns the bytes of the given Base58 string.
+    fn decode(data: &str) -> Result<Vec<u8>, AddressError> {
+        // The bytes are stored least significant first.
+        let mut bytes: Vec<u8> = vec![];
+        for character in data.chars() {
+            let mut carry = match BASE58_ALPHABET.iter().position(|c| *c as char == character) {
+                Some(index) => index as u32,
+                None => {
+                    return Err(AddressError::Message(format!(
+                        "invalid base58 character: {}",
+                        character
+                    )))
+                }
+            };
+            for byte in bytes.iter_mut() {
+                carry += *byte as u32 * 58;
+                *byte = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push(carry as u8);
+                carry >>= 8;
+            }
+        }
+
+        let zeros = data.chars().take_while(|c| *c == BASE58_ALPHABET[0] as char).count();
+        Ok((0..zeros).map(|_| 0).chain(bytes.into_iter().rev()).collect())
+    }
+}
+
+/// Returns the Base58Check encoding of the given 20-byte hash with the address prefix of the format.
+fn encode_base58<N: ZcashNetwork>(format: &ZcashFormat, payload: &[u8]) -> Result<String, AddressError> {
+    encode_base58_with::<CrateBase58, N>(format, payload)
+}
+
+/// Returns the Base58Check encoding of the given 20-byte hash with the address prefix of the format,
+/// using the given Base58 implementation.
+pub fn encode_base58_with<C: Base58Codec, N: ZcashNetwork>(
+    format: &ZcashFormat,
+    payload: &[u8],
+) -> Result<String, AddressError> {
+    if payload.len() != 20 {
+        return Err(AddressError::InvalidByteLength(payload.len()));
+    }
+
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(payload);
+    Ok(encode_hash_with::<C, N>(format, &hash))
+}
+
+/// Returns the Base58Check encoding of the given 20-byte hash with the address prefix of the format.
+/// A 20-byte hash always encodes, so unlike `AddressEncoder::encode` this cannot fail.
+pub(crate) fn encode_hash<N: ZcashNetwork>(format: &ZcashFormat, hash: &[u8; 20]) -> String {
+    encode_hash_with::<CrateBase58, N>(format, hash)
+}
+
+/// Returns the Base58Check encoding of the given 20-byte hash with the address prefix of the format,
+/// using the given Base58 implementation.
+fn encode_hash_with<C: Base58Codec, N: ZcashNetwork>(format: &ZcashFormat, hash: &[u8; 20]) -> String {
+    let mut address = N::to_address_prefix(format);
+    address.extend(hash);
+    let sum = checksum(&address)[0..4].to_vec();
+    address.extend(sum);
+
+    C::encode(&address)
+}
+
+/// Returns the 20-byte hash and network of the given Base58Check address of the format.
+fn decode_base58<N: ZcashNetwork>(format: &ZcashFormat, address: &str) -> Result<(Vec<u8>, N), AddressError> {
+    decode_base58_with::<CrateBase58, N>(format, address)
+}
+
+/// Returns the 20-byte hash and network of the given Base58Check address of the format,
+/// using the given Base58 implementation.
+pub fn decode_base58_with<C: Base58Codec, N: ZcashNetwork>(
+    format: &ZcashFormat,
+    address: &str,
+) -> Result<(Vec<u8>, N), AddressError> {
+    let data = C::decode(address)?;
+    if data.len() != 26 {
+        return Err(AddressError::InvalidByteLength(data.len()));
+    }
+
+    let prefix = N::to_address_prefix(format);
+    if data[0..2] != prefix[..] {
+        return Err(AddressError::InvalidPrefix(data[0..2].to_vec()));
+    }
+
+    let expected = &data[22..26];
+    let found = &checksum(&data[0..22])[0..4];
+    if expected != found {
+        return Err(AddressError::InvalidChecksum(expected.to_base58(), found.to_base58()));
+    }
+
+    Ok((data[2..22].to_vec(), N::from_address_prefix(&prefix)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_round_trip<E: AddressEncoder, N: ZcashNetwork>(payload: &[u8]) {
+        let address = E::encode::<N>(payload).unwrap();
+        let (decoded, network) = E::decode::<N>(&address).unwrap();
+        assert_eq!(payload, &decoded[..]);
+        assert_eq!(N::NAME, network.to_string());
+    }
+
+    #[test]
+    fn base58_p2pkh() {
+        let payload = [0x2a; 20];
+        test_round_trip::<Base58P2PKH, Mainnet>(&payload);
+        test_round_trip::<Base58P2PKH, Testnet>(&payload);
+
+        let address = Base58P2PKH::encode::<Mainnet>(&payload).unwrap();
+        assert!(address.starts_with("t1"));
+        assert!(Base58P2PKH::decode::<Testnet>(&address).is_err());
+        assert!(Base58P2SH::decode::<Mainnet>(&address).is_err());
+        assert!(Base58P2PKH::encode::<Mainnet>(&payload[1..]).is_err());
+    }
+
+    #[test]
+    fn base58_p2sh() {
+        let payload = [0x5c; 20];
+        test_round_trip::<Base58P2SH, Mainnet>(&payload);
+        test_round_trip::<Base58P2SH, Testnet>(&payload);
+
+        assert!(Base58P2SH::encode::<Mainnet>(&payload).unwrap().starts_with("t3"));
+        assert!(Base58P2SH::encode::<Testnet>(&payload).unwrap().starts_with("t2"));
+    }
+
+    #[test]
+    fn bech32_sapling() {
+        let address = "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf";
+        let (payload, _) = Bech32Sapling::decode::<Mainnet>(address).unwrap();
+        assert_eq!(address, Bech32Sapling::encode::<Mainnet>(&payload).unwrap());
+
+        test_round_trip::<Bech32Sapling, Mainnet>(&payload);
+        test_round_trip::<Bech32Sapling, Testnet>(&payload);
+        assert!(Bech32Sapling::decode::<Testnet>(address).is_err());
+    }
+
+    #[test]
+    fn base58_codecs() {
+        let payloads: [&[u8]; 7] = [
+            &[],
+            &[0],
+            &[0, 0, 1],
+            &[0xff; 8],
+            &[0x1c, 0xb8, 0x2a, 0x5c, 0x00, 0x00],
+            &hex!("1cb8b0a3c9c0f4e2e3c5130a61bb8a3e4c8ffc6b2f2bab850c3f0d944eb9fd6a"),
+            &hex!("80c7790fc56e6a2b8a9b6c0d84a9d4cbbb5df0d6c3bde0e4e52180ab54c3ef85d401"),
+        ];
+        payloads.iter().for_each(|payload| {
+            let encoded = CrateBase58::encode(payload);
+            assert_eq!(encoded, ReferenceBase58::encode(payload));
+            assert_eq!(payload.to_vec(), CrateBase58::decode(&encoded).unwrap());
+            assert_eq!(payload.to_vec(), ReferenceBase58::decode(&encoded).unwrap());
+        });
+
+        let payload = [0x2a; 20];
+        let address = encode_base58_with::<ReferenceBase58, Mainnet>(&ZcashFormat::P2PKH, &payload).unwrap();
+        assert_eq!(Base58P2PKH::encode::<Mainnet>(&payload).unwrap(), address);
+        let (decoded, _) = decode_base58_with::<ReferenceBase58, Mainnet>(&ZcashFormat::P2PKH, &address).unwrap();
+        assert_eq!(&payload[..], &decoded[..]);
+
+        assert!(CrateBase58::decode("t1O").is_err());
+        assert!(ReferenceBase58::decode("t1O").is_err());
+    }
+}
@@ -0,0 +1,335 @@
+use crate::network::ZcashNetwork;
+use crate::wallet::ZcashWallet;
+use wagyu_model::ExtendedPrivateKeyError;
+
+use argon2::{Algorithm, Argon2, AssociatedData, ParamsBuilder, Version};
+use crypto::{
+    aead::{AeadDecryptor, AeadEncryptor},
+    chacha20poly1305::ChaCha20Poly1305,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{fs::File, path::Path};
+
+/// The version of the encrypted wallet file format
+const ENCRYPTED_WALLET_VERSION: u8 = 1;
+
+/// The name of the key derivation function of the encrypted wallet file format
+const KDF_ALGORITHM: &str = "argon2id";
+/// The name of the cipher of the encrypted wallet file format
+const CIPHER_ALGORITHM: &str = "chacha20-poly1305";
+
+/// The Argon2id cost of new encrypted wallet files, 19 MiB of memory over 2 passes in 1 lane
+const ARGON2_PARAMETERS: Argon2Parameters = Argon2Parameters {
+    memory: 19 * 1024,
+    iterations: 2,
+    parallelism: 1,
+};
+/// The maximum memory cost in KiB accepted from an encrypted wallet file, 1 GiB
+const ARGON2_MAX_MEMORY: u32 = 1024 * 1024;
+/// The maximum number of passes over memory accepted from an encrypted wallet file
+const ARGON2_MAX_ITERATIONS: u32 = 64;
+/// The minimum byte length of a salt accepted from an encrypted wallet file, as required by Argon2
+const ARGON2_MIN_SALT_LENGTH: usize = 8;
+
+/// The byte lengths of the salt, nonce, key, and tag of an encrypted wallet file
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 8;
+const KEY_LENGTH: usize = 32;
+const TAG_LENGTH: usize = 16;
+
+/// Represents the cost parameters of Argon2id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Argon2Parameters {
+    /// The memory cost in KiB
+    pub(crate) memory: u32,
+    /// The number of passes over memory
+    pub(crate) iterations: u32,
+    /// The number of lanes
+    pub(crate) parallelism: u32,
+}
+
+/// The key derivation function of an encrypted wallet file
+#[derive(Serialize, Deserialize)]
+struct KdfHeader {
+    algorithm: String,
+    #[serde(flatten)]
+    parameters: Argon2Parameters,
+    salt: String,
+}
+
+/// The cipher of an encrypted wallet file
+#[derive(Serialize, Deserialize)]
+struct CipherHeader {
+    algorithm: String,
+    nonce: String,
+}
+
+/// The header of an encrypted wallet file, authenticated as the associated data of the cipher
+#[derive(Serialize, Deserialize)]
+struct EncryptedWalletHeader {
+    version: u8,
+    kdf: KdfHeader,
+    cipher: CipherHeader,
+}
+
+/// The serialized form of an encrypted wallet file
+#[derive(Serialize, Deserialize)]
+struct EncryptedWalletFile {
+    header: EncryptedWalletHeader,
+    ciphertext: String,
+    tag: String,
+}
+
+impl<N: ZcashNetwork> ZcashWallet<N> {
+    /// Writes the wallet to a file at the given path, encrypted with ChaCha20-Poly1305 under a key
+    /// derived from the passphrase with Argon2id. The versioned header of the file records the
+    /// parameters of both, and is authenticated along with the wallet.
+    /// The cipher is the original construction of ChaCha20-Poly1305 with a 64-bit nonce; every file
+    /// has a random salt, and so a distinct key, so the random nonce of a key is never reused.
+    pub fn save_encrypted<P: AsRef<Path>>(&self, path: P, passphrase: &str) -> Result<(), ExtendedPrivateKeyError> {
+        let mut plaintext = vec![];
+        self.save(&mut plaintext)?;
+
+        let rng = &mut rand::thread_rng();
+        let salt: [u8; SALT_LENGTH] = rng.gen();
+        let nonce: [u8; NONCE_LENGTH] = rng.gen();
+        let header = EncryptedWalletHeader {
+            version: ENCRYPTED_WALLET_VERSION,
+            kdf: KdfHeader {
+                algorithm: KDF_ALGORITHM.into(),
+                parameters: ARGON2_PARAMETERS,
+                salt: hex::encode(salt),
+            },
+            cipher: CipherHeader {
+                algorithm: CIPHER_ALGORITHM.into(),
+                nonce: hex::encode(nonce),
+            },
+        };
+
+        let key = argon2id(passphrase.as_bytes(), &salt, &[], &[], &ARGON2_PARAMETERS, KEY_LENGTH)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("argon2", format!("{:?}", error)))?;
+        let mut ciphertext = vec![0u8; plaintext.len()];
+        let mut tag = [0u8; TAG_LENGTH];
+        ChaCha20Poly1305::new(&key, &nonce, &to_json(&header)?).encrypt(&plaintext, &mut ciphertext, &mut tag);
+
+        let file = EncryptedWalletFile {
+            header,
+            ciphertext: hex::encode(ciphertext),
+            tag: hex::encode(tag),
+        };
+        serde_json::to_writer_pretty(File::create(path)?, &file)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))
+    }
+
+    /// Returns the wallet of the encrypted wallet file at the given path, decrypted with the passphrase.
+    /// Fails with `WrongPassphrase` if the wallet or its header does not authenticate, which is the
+    /// result of both a wrong passphrase and a tampered file.
+    pub fn load_encrypted<P: AsRef<Path>>(path: P, passphrase: &str) -> Result<Self, ExtendedPrivateKeyError> {
+        let file: EncryptedWalletFile = serde_json::from_reader(File::open(path)?)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))?;
+
+        let header = &file.header;
+        if header.version != ENCRYPTED_WALLET_VERSION {
+            return Err(ExtendedPrivateKeyError::InvalidVersionBytes(vec![header.version]));
+        }
+        if header.kdf.algorithm != KDF_ALGORITHM {
+            return Err(ExtendedPrivateKeyError::UnsupportedFormat(header.kdf.algorithm.clone()));
+        }
+        if header.cipher.algorithm != CIPHER_ALGORITHM {
+            return Err(ExtendedPrivateKeyError::UnsupportedFormat(
+                header.cipher.algorithm.clone(),
+            ));
+        }
+
+        let parameters = header.kdf.parameters;
+        // Argon2 requires at least 8 KiB of memory per lane.
+        let minimum_memory = parameters.parallelism.checked_mul(8);
+        if parameters.iterations == 0
+            || parameters.iterations > ARGON2_MAX_ITERATIONS
+            || parameters.parallelism == 0
+            || minimum_memory.is_none_or(|minimum| parameters.memory < minimum)
+            || parameters.memory > ARGON2_MAX_MEMORY
+        {
+            return Err(ExtendedPrivateKeyError::Message(format!(
+                "invalid argon2id parameters: {:?}",
+                parameters
+            )));
+        }
+
+        let salt = from_hex(&header.kdf.salt)?;
+        let nonce = from_hex(&header.cipher.nonce)?;
+        let ciphertext = from_hex(&file.ciphertext)?;
+        let tag = from_hex(&file.tag)?;
+        if salt.len() < ARGON2_MIN_SALT_LENGTH {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(salt.len()));
+        }
+        if nonce.len() != NONCE_LENGTH {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(nonce.len()));
+        }
+        if tag.len() != TAG_LENGTH {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(tag.len()));
+        }
+
+        let key = argon2id(passphrase.as_bytes(), &salt, &[], &[], &parameters, KEY_LENGTH)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("argon2", format!("{:?}", error)))?;
+        let mut plaintext = vec![0u8; ciphertext.len()];
+        if !ChaCha20Poly1305::new(&key, &nonce, &to_json(header)?).decrypt(&ciphertext, &mut plaintext, &tag) {
+            return Err(ExtendedPrivateKeyError::WrongPassphrase);
+        }
+
+        Self::load(&plaintext[..])
+    }
+}
+
+/// Returns the JSON serialization of the given header.
+fn to_json(header: &EncryptedWalletHeader) -> Result<Vec<u8>, ExtendedPrivateKeyError> {
+    serde_json::to_vec(header).map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))
+}
+
+/// Returns the bytes of the given hex string.
+fn from_hex(data: &str) -> Result<Vec<u8>, ExtendedPrivateKeyError> {
+    hex::decode(data).map_err(|error| ExtendedPrivateKeyError::Crate("hex", format!("{:?}", error)))
+}
+
+/// Returns the Argon2id (v1.3) tag of the given length of a password and salt,
+/// with an optional secret and associated data, as specified in RFC 9106.
+pub(crate) fn argon2id(
+    password: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    data: &[u8],
+    parameters: &Argon2Parameters,
+    length: usize,
+) -> Result<Vec<u8>, argon2::Error> {
+    let params = ParamsBuilder::new()
+        .m_cost(parameters.memory)
+        .t_cost(parameters.iterations)
+        .p_cost(parameters.parallelism)
+        .output_len(length)
+        .data(AssociatedData::new(data)?)
+        .build()?;
+    let mut tag = vec![0u8; length];
+    Argon2::new_with_secret(secret, Algorithm::Argon2id, Version::V0x13, params)?
+        .hash_password_into(password, salt, &mut tag)?;
+    Ok(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    use std::{env, fs, path::PathBuf};
+
+    /// Seed of "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    const SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+    /// Returns a path in the temporary directory unique to the given test.
+    fn temporary_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("wagyu-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_argon2id() {
+        // RFC 9106, section 5.3
+        let parameters = Argon2Parameters {
+            memory: 32,
+            iterations: 3,
+            parallelism: 4,
+        };
+        assert_eq!(
+            "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659",
+            hex::encode(argon2id(&[1; 32], &[2; 16], &[3; 8], &[4; 12], &parameters, 32).unwrap())
+        );
+
+        // The reference implementation of Argon2
+        let parameters = Argon2Parameters {
+            memory: 1 << 16,
+            iterations: 2,
+            parallelism: 1,
+        };
+        assert_eq!(
+            "09316115d5cf24ed5a15a31a3ba326e5cf32edc24702987c02b6566f61913cf7",
+            hex::encode(argon2id(b"password", b"somesalt", &[], &[], &parameters, 32).unwrap())
+        );
+    }
+
+    #[test]
+    fn save_encrypted() {
+        type N = Mainnet;
+
+        let seed = hex::decode(SEED).unwrap();
+        let mut wallet = ZcashWallet::<N>::new(&seed, 2).unwrap();
+        wallet.next_address().unwrap();
+
+        let path = temporary_path("save-encrypted");
+        wallet.save_encrypted(&path, "correct horse battery staple").unwrap();
+        let file = fs::read_to_string(&path).unwrap();
+        assert!(!file.contains(SEED));
+
+        let loaded = ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple").unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(wallet, loaded);
+    }
+
+    #[test]
+    fn load_encrypted_invalid() {
+        type N = Mainnet;
+
+        let seed = hex::decode(SEED).unwrap();
+        let path = temporary_path("load-encrypted-invalid");
+        ZcashWallet::<N>::new(&seed, 1)
+            .unwrap()
+            .save_encrypted(&path, "correct horse battery staple")
+            .unwrap();
+        let file = fs::read_to_string(&path).unwrap();
+
+        // Wrong passphrase
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "Correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::WrongPassphrase)
+        ));
+
+        // Tampered header
+        fs::write(&path, file.replacen("\"iterations\": 2", "\"iterations\": 3", 1)).unwrap();
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::WrongPassphrase)
+        ));
+
+        // Invalid version
+        fs::write(&path, file.replacen("\"version\": 1", "\"version\": 2", 1)).unwrap();
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::InvalidVersionBytes(_))
+        ));
+
+        // Crafted costs, whose minimum memory overflows, or whose passes exceed the maximum
+        let crafted = file.replacen("\"parallelism\": 1", "\"parallelism\": 4294967295", 1);
+        fs::write(&path, crafted).unwrap();
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::Message(_))
+        ));
+        let crafted = file.replacen("\"iterations\": 2", "\"iterations\": 4294967295", 1);
+        fs::write(&path, crafted).unwrap();
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::Message(_))
+        ));
+
+        // Short salt
+        let salt = file.split("\"salt\": \"").nth(1).unwrap().split('"').next().unwrap();
+        fs::write(&path, file.replacen(salt, &salt[..14], 1)).unwrap();
+        assert!(matches!(
+            ZcashWallet::<N>::load_encrypted(&path, "correct horse battery staple"),
+            Err(ExtendedPrivateKeyError::InvalidByteLength(7))
+        ));
+
+        // Invalid network
+        fs::write(&path, &file).unwrap();
+        assert!(ZcashWallet::<Testnet>::load_encrypted(&path, "correct horse battery staple").is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,342 @@
+use crate::format::ZcashFormat;
+use crate::network::{Mainnet, Testnet, ZcashNetwork};
+use crate::private_key::ZcashPrivateKey;
+use wagyu_model::{AddressError, Network, PrivateKey, PrivateKeyError};
+
+use std::{collections::BTreeMap, fmt, io::Write, str::FromStr};
+
+/// Represents a column of an exported batch of Zcash private keys
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExportColumn {
+    /// The encoded private key (WIF for transparent keys)
+    Wif,
+    /// The address of the private key
+    Address,
+    /// The network of the private key
+    Network,
+    /// If true, the private key is serialized in compressed form (transparent keys only)
+    Compressed,
+    /// The raw private key bytes in hex
+    Hex,
+}
+
+impl ExportColumn {
+    /// Returns the value of the column for the given private key.
+    pub fn to_value<N: ZcashNetwork>(&self, private_key: &ZcashPrivateKey<N>) -> Result<String, AddressError> {
+        Ok(match self {
+            ExportColumn::Wif => private_key.to_string(),
+            ExportColumn::Address => private_key.to_address(&to_format(private_key))?.to_string(),
+            ExportColumn::Network => N::NAME.to_string(),
+            ExportColumn::Compressed => match private_key {
+                ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key.is_compressed().to_string(),
+                _ => "".into(),
+            },
+            ExportColumn::Hex => match private_key {
+                ZcashPrivateKey::<N>::P2PKH(spending_key) => hex::encode(&spending_key.to_secp256k1_secret_key()[..]),
+                ZcashPrivateKey::<N>::P2SH(_) => "".into(),
+                ZcashPrivateKey::<N>::Sprout(spending_key) => hex::encode(spending_key.spending_key),
+                ZcashPrivateKey::<N>::Sapling(spending_key) => hex::encode(&spending_key.to_bytes()[..]),
+            },
+        })
+    }
+}
+
+impl fmt::Display for ExportColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportColumn::Wif => write!(f, "wif"),
+            ExportColumn::Address => write!(f, "address"),
+            ExportColumn::Network => write!(f, "network"),
+            ExportColumn::Compressed => write!(f, "compressed"),
+            ExportColumn::Hex => write!(f, "hex"),
+        }
+    }
+}
+
+/// Represents the output format of an exported batch of Zcash private keys
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExportFormat {
+    /// CSV of the WIF and address columns with a header row, as written by `export_csv`
+    Csv,
+    /// A compact JSON array of objects of the WIF and address of each private key
+    Json,
+    /// One WIF per line, as written by `stream_generate`
+    Plaintext,
+}
+
+/// The character length of a compressed WIF, the longer of the two WIF forms
+const WIF_LENGTH: usize = 52;
+/// The character length of a transparent P2PKH address
+const ADDRESS_LENGTH: usize = 35;
+
+/// Returns the estimated size in bytes of an export of `count` transparent private keys in the given format,
+/// for pre-allocating its file. The estimate assumes compressed WIFs, so it is exact for compressed keys
+/// and slightly above the size of uncompressed keys.
+pub fn estimated_export_size(count: usize, format: ExportFormat) -> usize {
+    match format {
+        // The header row, then the WIF, a comma, the address, and a line break of each row
+        ExportFormat::Csv => "wif,address\n".len() + count * (WIF_LENGTH + 1 + ADDRESS_LENGTH + 1),
+        // The brackets, then each `{"wif":"…","address":"…"}` object, separated by commas
+        ExportFormat::Json => {
+            let object = r#"{"wif":"","address":""}"#.len() + WIF_LENGTH + ADDRESS_LENGTH;
+            2 + count * object + count.saturating_sub(1)
+        }
+        // The WIF and a line break of each line
+        ExportFormat::Plaintext => count * (WIF_LENGTH + 1),
+    }
+}
+
+/// Writes the given private keys as CSV with a header row of the given columns.
+/// Addresses are derived in the default format of each private key, and a Sapling
+/// address is derived with a random diversifier.
+pub fn export_csv<N: ZcashNetwork, W: Write>(
+    private_keys: &[ZcashPrivateKey<N>],
+    columns: &[ExportColumn],
+    mut writer: W,
+) -> Result<(), AddressError> {
+    let header: Vec<String> = columns.iter().map(|column| escape_csv(&column.to_string())).collect();
+    writeln!(writer, "{}", header.join(","))?;
+
+    for private_key in private_keys {
+        let row = columns
+            .iter()
+            .map(|column| Ok(escape_csv(&column.to_value(private_key)?)))
+            .collect::<Result<Vec<String>, AddressError>>()?;
+        writeln!(writer, "{}", row.join(","))?;
+    }
+
+    Ok(())
+}
+
+/// Returns a Markdown table of the address of each of the given private keys, for a printed record
+/// of a cold storage wallet. If `masked` is true, the table includes each WIF with all but its first
+/// and last four characters masked; the complete private keys are never included.
+pub fn to_markdown<N: ZcashNetwork>(private_keys: &[ZcashPrivateKey<N>], masked: bool) -> Result<String, AddressError> {
+    let mut markdown = match masked {
+        true => String::from("| # | Address | WIF |\n| --- | --- | --- |\n"),
+        false => String::from("| # | Address |\n| --- | --- |\n"),
+    };
+
+    for (index, private_key) in private_keys.iter().enumerate() {
+        let address = private_key.to_address(&to_format(private_key))?;
+        match masked {
+            true => markdown += &format!("| {} | `{}` | `{}` |\n", index, address, private_key.wif_masked()),
+            false => markdown += &format!("| {} | `{}` |\n", index, address),
+        }
+    }
+
+    Ok(markdown)
+}
+
+/// Returns the given encoded private keys grouped by the name of their network, in input order.
+/// Sapling expanded spending keys carry no network and are grouped with mainnet.
+pub fn group_by_network<'a>(private_keys: &[&'a str]) -> Result<BTreeMap<&'static str, Vec<&'a str>>, PrivateKeyError> {
+    let mut groups = BTreeMap::new();
+    for private_key in private_keys {
+        let network = match ZcashPrivateKey::<Mainnet>::from_str(private_key) {
+            Ok(_) => Mainnet::NAME,
+            Err(_) => {
+                ZcashPrivateKey::<Testnet>::from_str(private_key)?;
+                Testnet::NAME
+            }
+        };
+        groups.entry(network).or_insert_with(Vec::new).push(*private_key);
+    }
+    Ok(groups)
+}
+
+/// Returns the default address format of the given private key.
+fn to_format<N: ZcashNetwork>(private_key: &ZcashPrivateKey<N>) -> ZcashFormat {
+    match private_key {
+        ZcashPrivateKey::<N>::P2PKH(_) => ZcashFormat::P2PKH,
+        ZcashPrivateKey::<N>::P2SH(_) => ZcashFormat::P2SH,
+        ZcashPrivateKey::<N>::Sprout(_) => ZcashFormat::Sprout,
+        ZcashPrivateKey::<N>::Sapling(_) => ZcashFormat::Sapling(None),
+    }
+}
+
+/// Returns the CSV field for a given value, quoting it if it contains a delimiter,
+/// quote, or line break.
+fn escape_csv(value: &str) -> String {
+    match value.contains(&[',', '"', '\n', '\r'][..]) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the fields of each line of a CSV document.
+    fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+        csv.lines()
+            .map(|line| {
+                let mut fields = vec![];
+                let mut field = String::new();
+                let mut quoted = false;
+                let mut chars = line.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match (c, quoted) {
+                        ('"', true) if chars.peek() == Some(&'"') => {
+                            field.push('"');
+                            chars.next();
+                        }
+                        ('"', _) => quoted = !quoted,
+                        (',', false) => fields.push(std::mem::take(&mut field)),
+                        _ => field.push(c),
+                    }
+                }
+                fields.push(field);
+                fields
+            })
+            .collect()
+    }
+
+    #[test]
+    fn csv() {
+        type N = Mainnet;
+
+        let keys = [
+            (
+                "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+                "t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod",
+                "true",
+            ),
+            (
+                "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+                "t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp",
+                "false",
+            ),
+        ];
+        let private_keys: Vec<ZcashPrivateKey<N>> = keys
+            .iter()
+            .map(|(wif, _, _)| ZcashPrivateKey::<N>::from_str(wif).unwrap())
+            .collect();
+
+        let columns = [
+            ExportColumn::Address,
+            ExportColumn::Wif,
+            ExportColumn::Compressed,
+            ExportColumn::Network,
+        ];
+        let mut buffer = vec![];
+        export_csv(&private_keys, &columns, &mut buffer).unwrap();
+
+        let rows = parse_csv(&String::from_utf8(buffer).unwrap());
+        assert_eq!(3, rows.len());
+        assert_eq!(vec!["address", "wif", "compressed", "network"], rows[0]);
+        keys.iter()
+            .zip(&rows[1..])
+            .for_each(|((wif, address, compressed), row)| {
+                assert_eq!(vec![*address, *wif, *compressed, "mainnet"], *row);
+            });
+    }
+
+    #[test]
+    fn estimated_export_size() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        let private_keys = crate::batch::generate_batch::<N, _>(rng, 10).unwrap();
+
+        let mut csv = vec![];
+        export_csv(&private_keys, &[ExportColumn::Wif, ExportColumn::Address], &mut csv).unwrap();
+        assert_eq!(csv.len(), super::estimated_export_size(10, ExportFormat::Csv));
+
+        let objects: Vec<BTreeMap<&str, String>> = private_keys
+            .iter()
+            .map(|private_key| {
+                let mut object = BTreeMap::new();
+                object.insert("wif", ExportColumn::Wif.to_value(private_key).unwrap());
+                object.insert("address", ExportColumn::Address.to_value(private_key).unwrap());
+                object
+            })
+            .collect();
+        let json = serde_json::to_string(&objects).unwrap();
+        assert_eq!(json.len(), super::estimated_export_size(10, ExportFormat::Json));
+
+        let mut plaintext = vec![];
+        crate::batch::stream_generate::<N, _, _>(rng, true, 10, &mut plaintext).unwrap();
+        assert_eq!(
+            plaintext.len(),
+            super::estimated_export_size(10, ExportFormat::Plaintext)
+        );
+
+        // Uncompressed WIFs are one character shorter, so the estimate bounds their size from above.
+        let mut plaintext = vec![];
+        crate::batch::stream_generate::<N, _, _>(rng, false, 10, &mut plaintext).unwrap();
+        let estimate = super::estimated_export_size(10, ExportFormat::Plaintext);
+        assert!(plaintext.len() <= estimate && estimate - plaintext.len() <= 10);
+
+        assert_eq!(2, super::estimated_export_size(0, ExportFormat::Json));
+    }
+
+    #[test]
+    fn markdown() {
+        type N = Mainnet;
+
+        let private_keys = [
+            "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+            "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+        ];
+        let private_keys: Vec<ZcashPrivateKey<N>> = private_keys
+            .iter()
+            .map(|wif| ZcashPrivateKey::<N>::from_str(wif).unwrap())
+            .collect();
+
+        let markdown = to_markdown(&private_keys, false).unwrap();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(
+            vec![
+                "| # | Address |",
+                "| --- | --- |",
+                "| 0 | `t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod` |",
+                "| 1 | `t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp` |",
+            ],
+            lines
+        );
+
+        let markdown = to_markdown(&private_keys, true).unwrap();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(4, lines.len());
+        assert_eq!("| # | Address | WIF |", lines[0]);
+        assert_eq!(
+            "| 0 | `t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod` | `L***…***VH4g` |",
+            lines[2]
+        );
+        assert!(!markdown.contains(&private_keys[0].to_string()));
+
+        assert_eq!(2, to_markdown::<N>(&[], true).unwrap().lines().count());
+    }
+
+    #[test]
+    fn group_by_network() {
+        let private_keys = [
+            "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+            "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M",
+            "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+            "92VN8AQdnRoBRw7QQcpUYsh1bSEWUrkZ364fjMUdNrm8fTJhEGw",
+            "secret-spending-key-test1fygsm6l3ltqqs63040tq426p85pgzuetdeg6q0wka38nx3rfa70sa9qp0v",
+        ];
+
+        let groups = super::group_by_network(&private_keys).unwrap();
+        assert_eq!(2, groups.len());
+        assert_eq!(vec![private_keys[0], private_keys[2]], groups["mainnet"]);
+        assert_eq!(
+            vec![private_keys[1], private_keys[3], private_keys[4]],
+            groups["testnet"]
+        );
+
+        assert!(super::group_by_network(&[]).unwrap().is_empty());
+        assert!(super::group_by_network(&[&private_keys[0][1..]]).is_err());
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!("plain", escape_csv("plain"));
+        assert_eq!("\"a,b\"", escape_csv("a,b"));
+        assert_eq!("\"say \"\"hi\"\"\"", escape_csv("say \"hi\""));
+        assert_eq!(vec![vec!["a,b", "say \"hi\""]], parse_csv("\"a,b\",\"say \"\"hi\"\"\""));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::network::ZcashNetwork;
 use wagyu_model::{AddressError, Format};
 
+use base58::ToBase58;
 use serde::Serialize;
 use std::fmt;
 
@@ -20,11 +21,78 @@ pub enum ZcashFormat {
 impl Format for ZcashFormat {}
 
 impl ZcashFormat {
+    /// Returns the supported address formats, with Sapling addresses of a random diversifier.
+    pub fn all() -> &'static [ZcashFormat] {
+        &[
+            ZcashFormat::P2PKH,
+            ZcashFormat::P2SH,
+            ZcashFormat::Sprout,
+            ZcashFormat::Sapling(None),
+        ]
+    }
+
     /// Returns the address prefix of the given network.
     pub fn to_address_prefix<N: ZcashNetwork>(&self) -> Vec<u8> {
         N::to_address_prefix(self)
     }
 
+    /// Returns the minimum and maximum character length of an address of the format on the given network.
+    /// Base58 addresses vary in length with the value of their payload, while Bech32 addresses do not.
+    pub fn address_length_range<N: ZcashNetwork>(&self) -> (usize, usize) {
+        let prefix = N::to_address_prefix(self);
+        // The length of the hash or keys and the 4-byte checksum following the prefix
+        let length = match self {
+            ZcashFormat::P2PKH | ZcashFormat::P2SH => 24,
+            ZcashFormat::Sprout => 68,
+            // The human-readable part, the separator, 43 bytes as 69 base32 characters, and the 6-character checksum
+            ZcashFormat::Sapling(_) => {
+                let length = prefix.len() + 1 + 69 + 6;
+                return (length, length);
+            }
+        };
+
+        let bound = |byte: u8| [&prefix[..], &vec![byte; length]].concat().to_base58().len();
+        (bound(0x00), bound(0xff))
+    }
+
+    /// Returns the leading characters shared by every address of the format on the given network,
+    /// which are fixed by its prefix and therefore cannot be chosen in a vanity search.
+    pub fn fixed_prefix<N: ZcashNetwork>(&self) -> String {
+        let prefix = N::to_address_prefix(self);
+        let length = match self {
+            ZcashFormat::P2PKH | ZcashFormat::P2SH => 24,
+            ZcashFormat::Sprout => 68,
+            // The human-readable part and the separator
+            ZcashFormat::Sapling(_) => return format!("{}1", String::from_utf8_lossy(&prefix)),
+        };
+
+        // Base58 preserves the order of payloads of equal encoded length, so the characters shared by
+        // the smallest and largest encodings are shared by every encoding between them.
+        let min = [&prefix[..], &vec![0x00; length]].concat().to_base58();
+        let max = [&prefix[..], &vec![0xff; length]].concat().to_base58();
+        if min.len() != max.len() {
+            return String::new();
+        }
+        min.chars()
+            .zip(max.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    }
+
+    /// Returns `true` if the format is a transparent address format.
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            ZcashFormat::P2PKH | ZcashFormat::P2SH => true,
+            ZcashFormat::Sprout | ZcashFormat::Sapling(_) => false,
+        }
+    }
+
+    /// Returns `true` if the format is a shielded address format.
+    pub fn is_shielded(&self) -> bool {
+        !self.is_transparent()
+    }
+
     /// Returns the format of the given address prefix.
     pub fn from_address_prefix(prefix: &Vec<u8>) -> Result<Self, AddressError> {
         if prefix.len() < 2 {
@@ -51,3 +119,52 @@ impl fmt::Display for ZcashFormat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    #[test]
+    fn test_address_length_range() {
+        let (min, max) = ZcashFormat::P2PKH.address_length_range::<Mainnet>();
+        assert!(min <= max);
+        assert!((min..=max).contains(&"t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp".len()));
+
+        let (min, max) = ZcashFormat::Sprout.address_length_range::<Mainnet>();
+        let address = "zcJLC7a3aRJohMNCVjSZQ8jFuofhAHJNAY4aX5soDkYfgNejzKnEZbucJmVibLWCwK8dyyfDhNhf3foXDDTouweC382LcX5";
+        assert!((min..=max).contains(&address.len()));
+
+        let address = "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf";
+        assert_eq!(
+            (address.len(), address.len()),
+            ZcashFormat::Sapling(None).address_length_range::<Mainnet>()
+        );
+        let (min, max) = ZcashFormat::Sapling(None).address_length_range::<Testnet>();
+        assert_eq!((88, 88), (min, max));
+    }
+
+    #[test]
+    fn test_all() {
+        let formats = ZcashFormat::all();
+        assert!(formats.contains(&ZcashFormat::P2PKH));
+        assert!(formats.contains(&ZcashFormat::Sapling(None)));
+        assert_eq!(4, formats.len());
+        assert_eq!(2, formats.iter().filter(|format| format.is_transparent()).count());
+    }
+
+    #[test]
+    fn test_fixed_prefix() {
+        assert_eq!("t1", ZcashFormat::P2PKH.fixed_prefix::<Mainnet>());
+        assert_eq!("t3", ZcashFormat::P2SH.fixed_prefix::<Mainnet>());
+        assert_eq!("tm", ZcashFormat::P2PKH.fixed_prefix::<Testnet>());
+        assert_eq!("t2", ZcashFormat::P2SH.fixed_prefix::<Testnet>());
+        assert_eq!("zs1", ZcashFormat::Sapling(None).fixed_prefix::<Mainnet>());
+        assert_eq!("ztestsapling1", ZcashFormat::Sapling(None).fixed_prefix::<Testnet>());
+
+        let prefix = ZcashFormat::Sprout.fixed_prefix::<Mainnet>();
+        assert!(prefix.starts_with("zc"));
+        let address = "zcJLC7a3aRJohMNCVjSZQ8jFuofhAHJNAY4aX5soDkYfgNejzKnEZbucJmVibLWCwK8dyyfDhNhf3foXDDTouweC382LcX5";
+        assert!(address.starts_with(&prefix));
+    }
+}
@@ -14,12 +14,28 @@ extern crate lazy_static;
 pub mod address;
 pub use self::address::*;
 
+pub mod address_book;
+pub use self::address_book::*;
+
 pub mod amount;
 pub use self::amount::*;
 
+pub mod armor;
+
+pub mod batch;
+pub use self::batch::*;
+
 pub mod derivation_path;
 pub use self::derivation_path::*;
 
+pub mod encoder;
+pub use self::encoder::*;
+
+pub mod encrypted_wallet;
+
+pub mod export;
+pub use self::export::*;
+
 pub mod extended_private_key;
 pub use self::extended_private_key::*;
 
@@ -32,11 +48,40 @@ pub use self::format::*;
 #[cfg_attr(tarpaulin, skip)]
 pub mod librustzcash;
 
+pub mod mnemonic;
+pub use self::mnemonic::*;
+
+pub mod monero_mnemonic;
+pub use self::monero_mnemonic::*;
+
 pub mod network;
 pub use self::network::*;
 
 pub mod private_key;
 pub use self::private_key::*;
 
+pub mod private_key_lite;
+pub use self::private_key_lite::*;
+
 pub mod public_key;
 pub use self::public_key::*;
+
+pub mod signature;
+pub use self::signature::*;
+
+pub mod slip39;
+pub use self::slip39::*;
+
+pub mod sweep;
+pub use self::sweep::*;
+
+pub mod transparent_extended_private_key;
+pub use self::transparent_extended_private_key::*;
+
+pub mod vanity;
+
+pub mod wallet;
+pub use self::wallet::*;
+
+pub mod wordlist;
+pub use self::wordlist::*;
@@ -0,0 +1,457 @@
+use crate::address::ZcashAddress;
+use crate::extended_private_key::ZcashExtendedPrivateKey;
+use crate::extended_public_key::ZcashExtendedPublicKey;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use crate::public_key::ZcashPublicKey;
+use crate::transparent_extended_private_key::ZcashTransparentExtendedPrivateKey;
+use crate::wordlist::ZcashWordlist;
+use wagyu_model::{
+    normalize::nfkd, ChildIndex, ExtendedPrivateKey, ExtendedPrivateKeyError, Mnemonic, MnemonicCount, MnemonicError,
+    MnemonicExtended,
+};
+
+use crypto::{
+    digest::Digest,
+    hmac::Hmac,
+    pbkdf2::pbkdf2,
+    sha2::{Sha256, Sha512},
+};
+use rand::Rng;
+use std::{fmt, marker::PhantomData, str, str::FromStr};
+
+const PBKDF2_ROUNDS: u32 = 2048;
+const PBKDF2_BYTES: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Represents a Zcash BIP39 mnemonic
+pub struct ZcashMnemonic<N: ZcashNetwork, W: ZcashWordlist> {
+    /// Initial entropy in multiples of 32 bits
+    entropy: Vec<u8>,
+    /// PhantomData
+    _network: PhantomData<N>,
+    /// PhantomData
+    _wordlist: PhantomData<W>,
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> MnemonicCount for ZcashMnemonic<N, W> {
+    /// Returns a new mnemonic given the word count.
+    fn new_with_count<R: Rng>(rng: &mut R, word_count: u8) -> Result<Self, MnemonicError> {
+        let length: usize = match word_count {
+            12 => 16,
+            15 => 20,
+            18 => 24,
+            21 => 28,
+            24 => 32,
+            wc => return Err(MnemonicError::InvalidWordCount(wc)),
+        };
+
+        let entropy: [u8; 32] = rng.gen();
+
+        Ok(Self {
+            entropy: entropy[0..length].to_vec(),
+            _network: PhantomData,
+            _wordlist: PhantomData,
+        })
+    }
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> Mnemonic for ZcashMnemonic<N, W> {
+    type Address = ZcashAddress<N>;
+    type Format = ZcashFormat;
+    type PrivateKey = ZcashPrivateKey<N>;
+    type PublicKey = ZcashPublicKey<N>;
+
+    /// Returns a new mnemonic.
+    fn new<R: Rng>(rng: &mut R) -> Result<Self, MnemonicError> {
+        let entropy: [u8; 16] = rng.gen();
+        Ok(Self {
+            entropy: entropy.to_vec(),
+            _network: PhantomData,
+            _wordlist: PhantomData,
+        })
+    }
+
+    /// Returns the mnemonic for the given phrase.
+    fn from_phrase(phrase: &str) -> Result<Self, MnemonicError> {
+        let mnemonic = phrase.split(' ').collect::<Vec<&str>>();
+
+        let length = match mnemonic.len() {
+            12 => 128,
+            15 => 160,
+            18 => 192,
+            21 => 224,
+            24 => 256,
+            wc => return Err(MnemonicError::InvalidWordCount(wc as u8)),
+        };
+
+        // Concatenate the 11-bit word indices and keep the leading entropy bits
+        let mut bits = Vec::with_capacity(mnemonic.len() * 11);
+        for word in mnemonic {
+            let index = W::get_index(word)?;
+            bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        let mnemonic = Self {
+            entropy: bits[..length]
+                .chunks(8)
+                .map(|byte| byte.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+                .collect(),
+            _network: PhantomData,
+            _wordlist: PhantomData,
+        };
+
+        // Ensures the checksum word matches the checksum word in the given phrase.
+        match phrase == mnemonic.to_phrase()? {
+            true => Ok(mnemonic),
+            false => Err(MnemonicError::InvalidPhrase(phrase.into())),
+        }
+    }
+
+    /// Returns the phrase of the corresponding mnemonic.
+    fn to_phrase(&self) -> Result<String, MnemonicError> {
+        let length: usize = match self.entropy.len() {
+            16 => 12,
+            20 => 15,
+            24 => 18,
+            28 => 21,
+            32 => 24,
+            entropy_len => return Err(MnemonicError::InvalidEntropyLength(entropy_len)),
+        };
+
+        // Compute the checksum by taking the first ENT / 32 bits of the SHA256 hash
+        let mut sha256 = Sha256::new();
+        sha256.input(&self.entropy);
+        let mut hash = [0u8; 32];
+        sha256.result(&mut hash);
+
+        // Convert the entropy bytes into bits and append the checksum
+        let mut encoding = self
+            .entropy
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect::<Vec<bool>>();
+        encoding.extend((0..length / 3).map(|i| (hash[0] >> (7 - i)) & 1 == 1));
+
+        // Compute the phrase in 11 bit chunks which encode an index into the word list
+        let wordlist = W::get_all();
+        let phrase = encoding
+            .chunks(11)
+            .map(|index| wordlist[index.iter().fold(0usize, |index, bit| (index << 1) | *bit as usize)])
+            .collect::<Vec<&str>>();
+
+        Ok(phrase.join(" "))
+    }
+
+    /// Returns the private key of the corresponding mnemonic.
+    fn to_private_key(&self, password: Option<&str>) -> Result<Self::PrivateKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_private_key())
+    }
+
+    /// Returns the public key of the corresponding mnemonic.
+    fn to_public_key(&self, password: Option<&str>) -> Result<Self::PublicKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_public_key())
+    }
+
+    /// Returns the address of the corresponding mnemonic.
+    fn to_address(&self, password: Option<&str>, format: &Self::Format) -> Result<Self::Address, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_address(format)?)
+    }
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> MnemonicExtended for ZcashMnemonic<N, W> {
+    type ExtendedPrivateKey = ZcashExtendedPrivateKey<N>;
+    type ExtendedPublicKey = ZcashExtendedPublicKey<N>;
+
+    /// Returns the ZIP32 Sapling extended private key of the corresponding mnemonic.
+    fn to_extended_private_key(&self, password: Option<&str>) -> Result<Self::ExtendedPrivateKey, MnemonicError> {
+        Ok(Self::ExtendedPrivateKey::new_master(
+            self.to_seed(password)?.as_slice(),
+            &ZcashFormat::Sapling(None),
+        )?)
+    }
+
+    /// Returns the ZIP32 Sapling extended public key of the corresponding mnemonic.
+    fn to_extended_public_key(&self, password: Option<&str>) -> Result<Self::ExtendedPublicKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_extended_public_key())
+    }
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> ZcashMnemonic<N, W> {
+    /// Compares the given phrase against the phrase extracted from its entropy.
+    pub fn verify_phrase(phrase: &str) -> bool {
+        Self::from_phrase(phrase).is_ok()
+    }
+
+    /// Returns the BIP32 transparent master extended private key of the corresponding mnemonic.
+    pub fn to_transparent_extended_private_key(
+        &self,
+        password: Option<&str>,
+    ) -> Result<ZcashTransparentExtendedPrivateKey<N>, MnemonicError> {
+        Ok(ZcashTransparentExtendedPrivateKey::new_master(
+            self.to_seed(password)?.as_slice(),
+        )?)
+    }
+
+    /// Returns the first `count` transparent receiving addresses of the given account,
+    /// derived with the BIP44 path m/44'/{133', 1'}/{account}'/0/{index}.
+    pub fn addresses_for_account(
+        &self,
+        password: Option<&str>,
+        account: u32,
+        count: u32,
+    ) -> Result<Vec<ZcashAddress<N>>, MnemonicError> {
+        let chain = self.to_transparent_extended_private_key(password)?.derive(&[
+            ChildIndex::Hardened(44),
+            N::HD_COIN_TYPE,
+            ChildIndex::hardened(account).map_err(ExtendedPrivateKeyError::from)?,
+            ChildIndex::Normal(0),
+        ])?;
+
+        (0..count)
+            .map(|index| {
+                let index = ChildIndex::normal(index).map_err(ExtendedPrivateKeyError::from)?;
+                Ok(chain.derive(&[index])?.to_address()?)
+            })
+            .collect()
+    }
+
+    /// Returns a seed using the given password and mnemonic, both normalized to NFKD as BIP39 requires.
+    fn to_seed(&self, password: Option<&str>) -> Result<Vec<u8>, MnemonicError> {
+        let mut seed = vec![0u8; PBKDF2_BYTES];
+        let salt = nfkd(&format!("mnemonic{}", password.unwrap_or("")));
+        let mut mac = Hmac::new(Sha512::new(), nfkd(&self.to_phrase()?).as_bytes());
+        pbkdf2(&mut mac, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        Ok(seed)
+    }
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> FromStr for ZcashMnemonic<N, W> {
+    type Err = MnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_phrase(s)
+    }
+}
+
+impl<N: ZcashNetwork, W: ZcashWordlist> fmt::Display for ZcashMnemonic<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.to_phrase() {
+                Ok(phrase) => phrase,
+                _ => return Err(fmt::Error),
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use crate::wordlist::*;
+
+    fn test_new_with_count<N: ZcashNetwork, W: ZcashWordlist>(word_count: u8) {
+        let rng = &mut rand::thread_rng();
+        let mnemonic = ZcashMnemonic::<N, W>::new_with_count(rng, word_count).unwrap();
+        test_from_phrase::<N, W>(&mnemonic.entropy, &mnemonic.to_phrase().unwrap());
+    }
+
+    fn test_from_phrase<N: ZcashNetwork, W: ZcashWordlist>(expected_entropy: &[u8], phrase: &str) {
+        let mnemonic = ZcashMnemonic::<N, W>::from_phrase(phrase).unwrap();
+        assert_eq!(expected_entropy, &mnemonic.entropy[..]);
+        assert_eq!(phrase, mnemonic.to_phrase().unwrap());
+    }
+
+    fn test_to_phrase<N: ZcashNetwork, W: ZcashWordlist>(expected_phrase: &str, entropy: &[u8]) {
+        let mnemonic = ZcashMnemonic::<N, W> {
+            entropy: entropy.to_vec(),
+            _network: PhantomData,
+            _wordlist: PhantomData,
+        };
+        assert_eq!(expected_phrase, mnemonic.to_phrase().unwrap());
+    }
+
+    fn test_to_seed<N: ZcashNetwork, W: ZcashWordlist>(expected_seed: &str, password: Option<&str>, phrase: &str) {
+        let mnemonic = ZcashMnemonic::<N, W>::from_phrase(phrase).unwrap();
+        assert_eq!(expected_seed, hex::encode(mnemonic.to_seed(password).unwrap()));
+    }
+
+    fn test_addresses_for_account<N: ZcashNetwork, W: ZcashWordlist>(
+        expected_addresses: &[&str],
+        phrase: &str,
+        account: u32,
+    ) {
+        let mnemonic = ZcashMnemonic::<N, W>::from_phrase(phrase).unwrap();
+        let addresses = mnemonic
+            .addresses_for_account(None, account, expected_addresses.len() as u32)
+            .unwrap();
+        assert_eq!(
+            expected_addresses.to_vec(),
+            addresses.iter().map(|address| address.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    /// Test vectors from https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    mod english {
+        use super::*;
+
+        type N = Mainnet;
+        type W = English;
+
+        const PASSWORD: &str = "TREZOR";
+        const NO_PASSWORD_STR: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+        // (entropy, phrase, seed)
+        const KEYPAIRS: [(&str, &str, &str); 5] = [
+            (
+                "00000000000000000000000000000000",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+            (
+                "808080808080808080808080808080808080808080808080",
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+                "107d7c02a5aa6f38c58083ff74f04c607c2d2c0ecc55501dadd72d025b751bc27fe913ffb796f841c49b1d33b610cf0e91d3aa239027f5e99fe4ce9e5088cd65",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+                "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+            ),
+            (
+                "9e885d952ad362caeb4efe34a8e91bd2",
+                "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+                "274ddc525802f7c828d8ef7ddbcdc5304e87ac3535913611fbbfa986d0c9e5476c91689f9c8a54fd55bd38606aa6a8595ad213d4c9c9f9aca3fb217069a41028",
+            ),
+        ];
+
+        #[test]
+        fn new() {
+            let word_counts: [u8; 5] = [12, 15, 18, 21, 24];
+            word_counts.iter().for_each(|word_count| {
+                test_new_with_count::<N, W>(*word_count);
+            })
+        }
+
+        #[test]
+        fn from_phrase() {
+            KEYPAIRS.iter().for_each(|(entropy, phrase, _)| {
+                test_from_phrase::<N, W>(&hex::decode(entropy).unwrap(), phrase);
+            })
+        }
+
+        #[test]
+        fn to_phrase() {
+            KEYPAIRS.iter().for_each(|(entropy, phrase, _)| {
+                test_to_phrase::<N, W>(phrase, &hex::decode(entropy).unwrap());
+            })
+        }
+
+        #[test]
+        fn to_seed() {
+            KEYPAIRS.iter().for_each(|(_, phrase, expected_seed)| {
+                test_to_seed::<N, W>(expected_seed, Some(PASSWORD), phrase);
+            });
+        }
+
+        #[test]
+        fn to_seed_no_password() {
+            test_to_seed::<N, W>(NO_PASSWORD_STR, None, KEYPAIRS[0].1);
+        }
+
+        #[test]
+        fn to_seed_normalized() {
+            let mnemonic = ZcashMnemonic::<N, W>::from_phrase(KEYPAIRS[0].1).unwrap();
+            let composed = mnemonic.to_seed(Some("caf\u{e9}")).unwrap();
+            let decomposed = mnemonic.to_seed(Some("cafe\u{301}")).unwrap();
+            assert_eq!(composed, decomposed);
+            assert_ne!(composed, mnemonic.to_seed(Some("cafe")).unwrap());
+        }
+
+        #[test]
+        fn to_extended_private_key() {
+            let mnemonic = ZcashMnemonic::<N, W>::from_phrase(KEYPAIRS[0].1).unwrap();
+            let expected_extended_private_key = ZcashExtendedPrivateKey::<N>::new_master(
+                &hex::decode(NO_PASSWORD_STR).unwrap(),
+                &ZcashFormat::Sapling(None),
+            )
+            .unwrap();
+            assert_eq!(
+                expected_extended_private_key,
+                mnemonic.to_extended_private_key(None).unwrap()
+            );
+        }
+
+        #[test]
+        fn addresses_for_account() {
+            let phrase = KEYPAIRS[0].1;
+            test_addresses_for_account::<N, W>(
+                &[
+                    "t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F",
+                    "t1aQ2b1XszNVo15BguYLbQGqETBL9QZA8Jq",
+                    "t1gLHX58QpcZr2SfaMhc5XbxabE455KZhoA",
+                ],
+                phrase,
+                0,
+            );
+            test_addresses_for_account::<N, W>(&["t1Hxm2pmTLYuKhyLeZoSPjsHPFLWePSTDka"], phrase, 1);
+            test_addresses_for_account::<Testnet, W>(&["tmF1xjfhsSzhy55dmhorzTnKjtHhZmPKzts"], phrase, 0);
+        }
+    }
+
+    mod test_invalid {
+        use super::*;
+
+        type N = Mainnet;
+        type W = English;
+
+        const INVALID_WORD_COUNT: u8 = 11;
+        const INVALID_PHRASE_LENGTH: &str =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        const INVALID_PHRASE_WORD: &str =
+            "abandoz abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        const INVALID_PHRASE_CHECKSUM: &str =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+
+        #[test]
+        #[should_panic(expected = "InvalidWordCount(11)")]
+        fn new_with_count_invalid_word_count() {
+            let rng = &mut rand::thread_rng();
+            let _mnemonic = ZcashMnemonic::<N, W>::new_with_count(rng, INVALID_WORD_COUNT).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidWord(\"abandoz\")")]
+        fn from_phrase_invalid_word() {
+            let _mnemonic = ZcashMnemonic::<N, W>::from_phrase(INVALID_PHRASE_WORD).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidWordCount(13)")]
+        fn from_phrase_invalid_length() {
+            let _mnemonic = ZcashMnemonic::<N, W>::from_phrase(INVALID_PHRASE_LENGTH).unwrap();
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "InvalidPhrase(\"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon\")"
+        )]
+        fn from_phrase_invalid_checksum() {
+            let _mnemonic = ZcashMnemonic::<N, W>::from_phrase(INVALID_PHRASE_CHECKSUM).unwrap();
+        }
+
+        #[test]
+        fn verify_invalid_phrase() {
+            assert!(!ZcashMnemonic::<N, W>::verify_phrase(INVALID_PHRASE_LENGTH));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::network::ZcashNetwork;
+use crate::transparent_extended_private_key::ZcashTransparentExtendedPrivateKey;
+use wagyu_model::{monero::ENGLISH, MnemonicError};
+
+/// The number of words in the Monero wordlist
+const WORDLIST_LENGTH: usize = 1626;
+/// The number of leading characters that identify a word of the English Monero wordlist
+const PREFIX_LENGTH: usize = 3;
+/// The number of words in a Monero mnemonic, including the checksum word
+const WORD_COUNT: usize = 25;
+
+/// Returns the 32-byte seed of the given 25-word English Monero mnemonic, verifying its checksum word.
+/// As in Monero, words are identified by their first three characters. This is a convenience for
+/// deriving Zcash keys from a phrase a Monero user already holds, not part of any Zcash protocol.
+pub fn decode_monero_mnemonic(phrase: &str) -> Result<[u8; 32], MnemonicError> {
+    let words: Vec<String> = phrase.split_whitespace().map(to_trimmed).collect();
+    if words.len() != WORD_COUNT {
+        return Err(MnemonicError::InvalidWordCount(words.len().min(u8::MAX as usize) as u8));
+    }
+
+    let wordlist: Vec<String> = ENGLISH.lines().map(to_trimmed).collect();
+    let index = |word: &String| match wordlist.iter().position(|element| element == word) {
+        Some(index) => Ok(index),
+        None => Err(MnemonicError::InvalidWord(word.clone())),
+    };
+
+    // Each 3 words encode 4 bytes in little-endian order
+    let n = WORDLIST_LENGTH;
+    let mut seed = [0u8; 32];
+    for (chunk, bytes) in words[..WORD_COUNT - 1].chunks(3).zip(seed.chunks_mut(4)) {
+        let (w1, w2, w3) = (index(&chunk[0])?, index(&chunk[1])?, index(&chunk[2])?);
+        let x = w1 + n * (((n - w1) + w2) % n) + n * n * (((n - w2) + w3) % n);
+        if x % n != w1 || x > u32::MAX as usize {
+            return Err(MnemonicError::InvalidDecoding);
+        }
+        bytes.copy_from_slice(&(x as u32).to_le_bytes());
+    }
+
+    // The checksum word is the word at the index of the CRC32 of the trimmed words modulo their count
+    let expected = &words[crc32(words[..WORD_COUNT - 1].concat().as_bytes()) as usize % (WORD_COUNT - 1)];
+    let found = &words[WORD_COUNT - 1];
+    if expected != found {
+        return Err(MnemonicError::InvalidChecksumWord(expected.clone(), found.clone()));
+    }
+
+    Ok(seed)
+}
+
+/// Returns the BIP32 transparent master extended private key of the seed of the given Monero mnemonic.
+pub fn monero_mnemonic_to_transparent_extended_private_key<N: ZcashNetwork>(
+    phrase: &str,
+) -> Result<ZcashTransparentExtendedPrivateKey<N>, MnemonicError> {
+    Ok(ZcashTransparentExtendedPrivateKey::<N>::new_master(
+        &decode_monero_mnemonic(phrase)?,
+    )?)
+}
+
+/// Returns the first `PREFIX_LENGTH` characters of the given word.
+fn to_trimmed(word: &str) -> String {
+    word.chars().take(PREFIX_LENGTH).collect()
+}
+
+/// Returns the CRC32 (IEEE) checksum of the given bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xEDB8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    /// Test vectors of the Monero English wordlist, as used by the Monero crate
+    const KEYPAIRS: [(&str, &str); 2] = [
+        (
+            "82a13b87b69555ba976601302e2498aed4875185c87b9133bf8d214f16e9eb0b",
+            "reruns today hookup itself thorn nirvana symptoms jukebox patio unquoted sushi long diode digit rewind hacksaw obvious soothe nightly return agile hobby algebra awesome nirvana",
+        ),
+        (
+            "31e28ef4feca46915bdbf7b192af866e154cb7dbc704e9a39b6ce24ac89c1102",
+            "cafe aided wounded lumber hounded water yoyo gasp aerial merger ungainly gaze ruby yacht tell playful smash issued sifting whole erase anxiety dash deity sifting",
+        ),
+    ];
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(0, crc32(b""));
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+    }
+
+    #[test]
+    fn test_decode_monero_mnemonic() {
+        KEYPAIRS.iter().for_each(|(seed, phrase)| {
+            assert_eq!(*seed, hex::encode(decode_monero_mnemonic(phrase).unwrap()));
+        });
+
+        // Words are identified by their prefix, and may be separated by any whitespace
+        let (seed, phrase) = KEYPAIRS[0];
+        let trimmed = phrase.split(' ').map(to_trimmed).collect::<Vec<_>>().join("\n");
+        assert_eq!(seed, hex::encode(decode_monero_mnemonic(&trimmed).unwrap()));
+
+        let master = monero_mnemonic_to_transparent_extended_private_key::<Mainnet>(phrase).unwrap();
+        let expected = ZcashTransparentExtendedPrivateKey::<Mainnet>::new_master(&hex::decode(seed).unwrap()).unwrap();
+        assert_eq!(expected.to_string(), master.to_string());
+    }
+
+    #[test]
+    fn test_decode_monero_mnemonic_invalid() {
+        let (_, phrase) = KEYPAIRS[0];
+        let words: Vec<&str> = phrase.split(' ').collect();
+
+        // Invalid word count
+        assert!(decode_monero_mnemonic(&words[..24].join(" ")).is_err());
+
+        // Invalid checksum word
+        let mut invalid = words.clone();
+        invalid[24] = "reruns";
+        assert!(decode_monero_mnemonic(&invalid.join(" ")).is_err());
+
+        // Invalid word
+        let mut invalid = words.clone();
+        invalid[0] = "abracadabra";
+        assert!(decode_monero_mnemonic(&invalid.join(" ")).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::ZcashFormat;
-use wagyu_model::{AddressError, ChildIndex, Network, NetworkError, PrivateKeyError};
+use wagyu_model::{AddressError, ChildIndex, ExtendedPrivateKeyError, Network, NetworkError, PrivateKeyError};
 
 use serde::Serialize;
 use std::{fmt, str::FromStr};
@@ -14,6 +14,7 @@ impl Network for Mainnet {
 
 impl ZcashNetwork for Mainnet {
     const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(133);
+    const IS_MAINNET: bool = true;
 
     /// Returns the address prefix of the given network.
     fn to_address_prefix(format: &ZcashFormat) -> Vec<u8> {
@@ -50,6 +51,11 @@ impl ZcashNetwork for Mainnet {
         }
     }
 
+    /// Returns `true`, as private keys are compressed by default.
+    fn default_compression() -> bool {
+        true
+    }
+
     /// Returns the prefix for a Sprout spending key.
     fn to_sprout_spending_key_prefix() -> [u8; 2] {
         [0xAB, 0x36]
@@ -99,6 +105,27 @@ impl ZcashNetwork for Mainnet {
             _ => return Err(NetworkError::InvalidExtendedPublicKeyPrefix(prefix.into())),
         }
     }
+
+    /// Returns the BIP32 version bytes of a transparent extended private key ("xprv").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_private_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x88, 0xAD, 0xE4]
+    }
+
+    /// Returns the network of the given BIP32 version bytes of a transparent extended private key.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn from_transparent_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix {
+            [0x04, 0x88, 0xAD, 0xE4] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the BIP32 version bytes of a transparent extended public key ("xpub").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_public_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x88, 0xB2, 0x1E]
+    }
 }
 
 impl FromStr for Mainnet {
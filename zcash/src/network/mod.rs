@@ -1,16 +1,26 @@
 use crate::format::ZcashFormat;
-use wagyu_model::{AddressError, ChildIndex, Network, NetworkError, PrivateKeyError};
+use wagyu_model::{AddressError, ChildIndex, ExtendedPrivateKeyError, Network, NetworkError, PrivateKeyError};
 
 pub mod mainnet;
 pub use self::mainnet::*;
 
+pub mod regtest;
+pub use self::regtest::*;
+
 pub mod testnet;
 pub use self::testnet::*;
 
+/// Returns the names of the supported Zcash networks.
+pub fn all_networks() -> &'static [&'static str] {
+    &[Mainnet::NAME, Testnet::NAME, Regtest::NAME]
+}
+
 /// The interface for a Zcash network.
 pub trait ZcashNetwork: Network {
     const HD_PURPOSE: ChildIndex = ChildIndex::Hardened(32);
     const HD_COIN_TYPE: ChildIndex;
+    /// Whether the network is the Zcash mainnet, rather than a test network
+    const IS_MAINNET: bool;
 
     /// Returns the address prefix of the given network.
     fn to_address_prefix(format: &ZcashFormat) -> Vec<u8>;
@@ -24,6 +34,9 @@ pub trait ZcashNetwork: Network {
     /// Returns the network of the given WIF prefix.
     fn from_wif_prefix(prefix: u8) -> Result<Self, PrivateKeyError>;
 
+    /// Returns `true` if newly generated private keys of the given network are compressed by default.
+    fn default_compression() -> bool;
+
     /// Returns the network prefix for a Sprout spending key.
     fn to_sprout_spending_key_prefix() -> [u8; 2];
 
@@ -47,4 +60,99 @@ pub trait ZcashNetwork: Network {
 
     /// Returns the network of the given extended public key prefix.
     fn from_extended_public_key_prefix(prefix: &str) -> Result<Self, NetworkError>;
+
+    /// Returns the BIP32 version bytes of a transparent extended private key of the given network.
+    fn to_transparent_extended_private_key_version_bytes() -> Vec<u8>;
+
+    /// Returns the network of the given BIP32 version bytes of a transparent extended private key.
+    fn from_transparent_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError>;
+
+    /// Returns the BIP32 version bytes of a transparent extended public key of the given network.
+    fn to_transparent_extended_public_key_version_bytes() -> Vec<u8>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::ZcashAddress;
+    use crate::private_key::ZcashPrivateKey;
+    use wagyu_model::PrivateKey;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_all_networks() {
+        let networks = all_networks();
+        assert_eq!(&["mainnet", "testnet", "regtest"], networks);
+        assert!(Mainnet::from_str(networks[0]).is_ok());
+        assert!(Testnet::from_str(networks[1]).is_ok());
+        assert!(Regtest::from_str(networks[2]).is_ok());
+
+        assert_eq!(
+            [true, false, false],
+            [Mainnet::IS_MAINNET, Testnet::IS_MAINNET, Regtest::IS_MAINNET]
+        );
+    }
+
+    #[test]
+    fn test_default_compression() {
+        fn test_new<N: ZcashNetwork>() {
+            let rng = &mut rand::thread_rng();
+            match ZcashPrivateKey::<N>::new(rng).unwrap() {
+                ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                    assert_eq!(N::default_compression(), spending_key.is_compressed())
+                }
+                _ => panic!("expected a P2PKH private key"),
+            }
+        }
+
+        assert!(Mainnet::default_compression());
+        assert!(Testnet::default_compression());
+        test_new::<Mainnet>();
+        test_new::<Testnet>();
+        test_new::<Regtest>();
+    }
+
+    #[test]
+    fn test_regtest_wif() {
+        type N = Regtest;
+
+        let wif = "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M";
+        let private_key = ZcashPrivateKey::<N>::from_str(wif).unwrap();
+        assert_eq!(wif, private_key.to_string());
+        assert_eq!(
+            "tmWT3bvWCHQkAXXucPjWHqLs9EyWUDdzSuN",
+            private_key.to_address(&ZcashFormat::P2PKH).unwrap().to_string()
+        );
+        assert!(ZcashPrivateKey::<Mainnet>::from_str(wif).is_err());
+
+        let rng = &mut rand::thread_rng();
+        let private_key = ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap();
+        assert!(private_key.to_string().starts_with('c'));
+        assert_eq!(
+            private_key,
+            ZcashPrivateKey::<N>::from_str(&private_key.to_string()).unwrap()
+        );
+
+        let address = private_key.to_address(&ZcashFormat::P2PKH).unwrap();
+        assert!(address.to_string().starts_with("tm"));
+        assert_eq!(address, ZcashAddress::<N>::from_str(&address.to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_regtest_sapling() {
+        type N = Regtest;
+
+        let private_key = ZcashPrivateKey::<N>::new_sapling(&mut rand::thread_rng()).unwrap();
+        assert!(private_key.to_string().starts_with("secret-spending-key-regtest1"));
+        assert_eq!(
+            private_key,
+            ZcashPrivateKey::<N>::from_str(&private_key.to_string()).unwrap()
+        );
+
+        let address = private_key.to_address(&ZcashFormat::Sapling(None)).unwrap();
+        assert!(address.to_string().starts_with("zregtestsapling1"));
+        assert_eq!(address, ZcashAddress::<N>::from_str(&address.to_string()).unwrap());
+        assert!(ZcashAddress::<Testnet>::from_str(&address.to_string()).is_err());
+    }
 }
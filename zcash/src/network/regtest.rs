@@ -0,0 +1,148 @@
+use super::*;
+use crate::format::ZcashFormat;
+use wagyu_model::{AddressError, ChildIndex, ExtendedPrivateKeyError, Network, NetworkError, PrivateKeyError};
+
+use serde::Serialize;
+use std::{fmt, str::FromStr};
+
+/// Represents the Zcash regression test network, which shares the Base58 prefixes and WIF prefix
+/// of the testnet and differs only in its Bech32 Sapling prefixes
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Regtest;
+
+impl Network for Regtest {
+    const NAME: &'static str = "regtest";
+}
+
+impl ZcashNetwork for Regtest {
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
+    const IS_MAINNET: bool = false;
+
+    /// Returns the address prefix of the given network.
+    fn to_address_prefix(format: &ZcashFormat) -> Vec<u8> {
+        match format {
+            ZcashFormat::P2PKH => vec![0x1D, 0x25],
+            ZcashFormat::P2SH => vec![0x1C, 0xBA],
+            ZcashFormat::Sprout => vec![0x16, 0xB6],
+            ZcashFormat::Sapling(_) => "zregtestsapling".as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the network of the given address prefix.
+    fn from_address_prefix(prefix: &Vec<u8>) -> Result<Self, AddressError> {
+        if prefix.len() < 2 {
+            return Err(AddressError::InvalidPrefixLength(prefix.len()));
+        }
+
+        match prefix[1] {
+            0x25 | 0xBA | 0xB6 | 0x72 => Ok(Self),
+            _ => Err(AddressError::InvalidPrefix(prefix.clone())),
+        }
+    }
+
+    /// Returns the WIF prefix of the given network.
+    fn to_wif_prefix() -> u8 {
+        0xEF
+    }
+
+    /// Returns the network of the given WIF prefix.
+    fn from_wif_prefix(prefix: u8) -> Result<Self, PrivateKeyError> {
+        match prefix {
+            0xEF => Ok(Self),
+            _ => Err(PrivateKeyError::InvalidPrefix(vec![prefix])),
+        }
+    }
+
+    /// Returns `true`, as private keys are compressed by default.
+    fn default_compression() -> bool {
+        true
+    }
+
+    /// Returns the prefix for a Sprout spending key.
+    fn to_sprout_spending_key_prefix() -> [u8; 2] {
+        [0xAC, 0x08]
+    }
+
+    /// Returns the prefix for a Sprout viewing key.
+    fn to_sprout_viewing_key_prefix() -> [u8; 3] {
+        [0xA8, 0xAC, 0x0C]
+    }
+
+    /// Returns the Sapling spending key prefix of the given network.
+    fn to_sapling_spending_key_prefix() -> String {
+        "secret-spending-key-regtest".into()
+    }
+
+    /// Returns the Sapling viewing key prefix of the given network.
+    fn to_sapling_viewing_key_prefix() -> String {
+        "zviewregtestsapling".into()
+    }
+
+    /// Returns the extended private key prefix of the given network.
+    /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-spending-keys
+    fn to_extended_private_key_prefix() -> String {
+        "secret-extended-key-regtest".into()
+    }
+
+    /// Returns the network of the given extended private key prefix.
+    /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-spending-keys
+    fn from_extended_private_key_prefix(prefix: &str) -> Result<Self, NetworkError> {
+        match prefix {
+            "secret-extended-key-regtest" => Ok(Self),
+            _ => Err(NetworkError::InvalidExtendedPrivateKeyPrefix(prefix.into())),
+        }
+    }
+
+    /// Returns the extended public key prefix of the given network.
+    /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-full-viewing-keys
+    fn to_extended_public_key_prefix() -> String {
+        "zxviewregtestsapling".into()
+    }
+
+    /// Returns the network of the given extended public key prefix.
+    /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-full-viewing-keys
+    fn from_extended_public_key_prefix(prefix: &str) -> Result<Self, NetworkError> {
+        match prefix {
+            "zxviewregtestsapling" => Ok(Self),
+            _ => Err(NetworkError::InvalidExtendedPublicKeyPrefix(prefix.into())),
+        }
+    }
+
+    /// Returns the BIP32 version bytes of a transparent extended private key ("tprv").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_private_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x35, 0x83, 0x94]
+    }
+
+    /// Returns the network of the given BIP32 version bytes of a transparent extended private key.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn from_transparent_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix {
+            [0x04, 0x35, 0x83, 0x94] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the BIP32 version bytes of a transparent extended public key ("tpub").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_public_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x35, 0x87, 0xCF]
+    }
+}
+
+impl FromStr for Regtest {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Regtest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::ZcashFormat;
-use wagyu_model::{AddressError, ChildIndex, Network, NetworkError, PrivateKeyError};
+use wagyu_model::{AddressError, ChildIndex, ExtendedPrivateKeyError, Network, NetworkError, PrivateKeyError};
 
 use serde::Serialize;
 use std::{fmt, str::FromStr};
@@ -13,6 +13,7 @@ impl Network for Testnet {
 
 impl ZcashNetwork for Testnet {
     const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
+    const IS_MAINNET: bool = false;
 
     /// Returns the address prefix of the given network.
     fn to_address_prefix(format: &ZcashFormat) -> Vec<u8> {
@@ -49,6 +50,11 @@ impl ZcashNetwork for Testnet {
         }
     }
 
+    /// Returns `true`, as private keys are compressed by default.
+    fn default_compression() -> bool {
+        true
+    }
+
     /// Returns the prefix for a Sprout spending key.
     fn to_sprout_spending_key_prefix() -> [u8; 2] {
         [0xAC, 0x08]
@@ -98,6 +104,27 @@ impl ZcashNetwork for Testnet {
             _ => return Err(NetworkError::InvalidExtendedPublicKeyPrefix(prefix.into())),
         }
     }
+
+    /// Returns the BIP32 version bytes of a transparent extended private key ("tprv").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_private_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x35, 0x83, 0x94]
+    }
+
+    /// Returns the network of the given BIP32 version bytes of a transparent extended private key.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn from_transparent_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix {
+            [0x04, 0x35, 0x83, 0x94] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the BIP32 version bytes of a transparent extended public key ("tpub").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn to_transparent_extended_public_key_version_bytes() -> Vec<u8> {
+        vec![0x04, 0x35, 0x87, 0xCF]
+    }
 }
 
 impl FromStr for Testnet {
@@ -1,4 +1,5 @@
 use crate::address::ZcashAddress;
+use crate::encoder::BASE58_ALPHABET;
 use crate::format::ZcashFormat;
 use crate::librustzcash::algebra::curve::bls12_381::Bls12;
 use crate::librustzcash::algebra::field::{PrimeField, PrimeFieldRepr};
@@ -9,20 +10,151 @@ use crate::librustzcash::sapling_crypto::{
 use crate::librustzcash::zip32::prf_expand;
 use crate::network::ZcashNetwork;
 use crate::public_key::ZcashPublicKey;
-use wagyu_model::{crypto::checksum, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+use crate::signature::{to_message_hash, ZcashSignature, SECP256K1};
+use wagyu_model::{
+    crypto::{checksum, ct_eq},
+    normalize::nfkd,
+    Address, AddressError, PrivateKey, PrivateKeyError, PublicKey,
+};
 
 use base58::{FromBase58, ToBase58};
 use bech32::{Bech32, FromBase32, ToBase32};
+use crypto::{
+    digest::Digest,
+    hkdf::{hkdf_expand, hkdf_extract},
+    scrypt::{scrypt, ScryptParams as Scrypt},
+    sha2::Sha256,
+};
 use rand::Rng;
 use secp256k1;
+use serde::Serialize;
 use std::{
+    any::type_name,
     cmp::{Eq, PartialEq},
     fmt::{self, Debug, Display},
     io::{self, Read, Write},
     marker::PhantomData,
     str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
+/// The HKDF salt of private keys derived from a counter and master secret
+const DERIVE_COUNTER_SALT: &[u8] = b"wagyu-zcash-derive-counter";
+
+/// The serialization form of newly generated P2PKH private keys.
+/// The default is compressed, which matches `PrivateKey::new` on every supported network;
+/// legacy uncompressed keys produce different WIFs and addresses for the same secret key.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompressionPolicy {
+    /// Generate keys in compressed form
+    #[default]
+    Compressed,
+    /// Generate keys in legacy uncompressed form
+    Uncompressed,
+}
+
+impl CompressionPolicy {
+    /// Returns the default compression policy of the given network.
+    pub fn for_network<N: ZcashNetwork>() -> Self {
+        match N::default_compression() {
+            true => CompressionPolicy::Compressed,
+            false => CompressionPolicy::Uncompressed,
+        }
+    }
+}
+
+/// The scrypt salt of private keys derived from a passphrase with `from_passphrase_with_scrypt`
+const PASSPHRASE_SCRYPT_SALT: &[u8] = b"wagyu-zcash-passphrase";
+
+/// The scrypt cost parameters of private keys derived from a passphrase.
+/// The default is the fixed cost of BIP38, N = 16384, r = 8, p = 8.
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScryptParams {
+    /// The CPU and memory cost, a power of two greater than 1
+    pub n: u64,
+    /// The block size
+    pub r: u32,
+    /// The parallelization
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self { n: 16384, r: 8, p: 8 }
+    }
+}
+
+impl ScryptParams {
+    /// Returns the scrypt parameters, verifying that N is a power of two greater than 1 and below 2^(16r),
+    /// and that r and p are positive with r * p below 2^30, as required by scrypt.
+    fn to_scrypt(self) -> Result<Scrypt, PrivateKeyError> {
+        let (r, p) = (u64::from(self.r), u64::from(self.p));
+        if self.n < 2 || !self.n.is_power_of_two() {
+            return Err(PrivateKeyError::Message(format!(
+                "invalid scrypt cost: {} is not a power of two greater than 1",
+                self.n
+            )));
+        }
+
+        let log_n = self.n.trailing_zeros() as u64;
+        if r == 0 || p == 0 || log_n >= r * 16 || r * p >= 1 << 30 || log_n >= usize::BITS as u64 {
+            return Err(PrivateKeyError::Message(format!(
+                "invalid scrypt parameters: {{ n: {}, r: {}, p: {} }}",
+                self.n, self.r, self.p
+            )));
+        }
+        Ok(Scrypt::new(log_n as u8, self.r, self.p))
+    }
+}
+
+/// The largest secp256k1 secret key, one less than the order of the curve
+const MAX_SECRET_KEY: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xba, 0xae, 0xdc,
+    0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x40,
+];
+
+/// Represents the provenance of a generated private key: the random number generator and time
+/// of its generation. It never contains the private key or any output of the generator.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    /// The type name of the random number generator
+    pub rng: String,
+    /// The time of generation in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// The name of the network of the private key
+    pub network: String,
+    /// If true, the private key is serialized in compressed form
+    pub compressed: bool,
+}
+
+/// Represents the byte layout of the WIF of a P2PKH private key, with each field in hex
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WifLayout {
+    /// The version byte of the network
+    pub version: String,
+    /// The 32 bytes of the secret key
+    pub secret_key: String,
+    /// The compression flag, present only in the WIF of a compressed private key
+    pub compression_flag: Option<String>,
+    /// The first 4 bytes of the double SHA256 of the preceding bytes
+    pub checksum: String,
+}
+
+/// Represents a paper wallet of a P2PKH secret key in both its compressed and uncompressed form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaperWallet<N: ZcashNetwork> {
+    /// The WIF of the compressed private key
+    pub compressed_wif: String,
+    /// The address of the compressed public key
+    pub compressed_address: ZcashAddress<N>,
+    /// The WIF of the uncompressed private key
+    pub uncompressed_wif: String,
+    /// The address of the uncompressed public key
+    pub uncompressed_address: ZcashAddress<N>,
+}
+
+/// Represents a Zcash P2PKH spending key. The WIF is not stored; it is encoded from the
+/// secret key, compression, and network whenever the key is displayed, so it cannot become stale.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct P2PKHSpendingKey<N: ZcashNetwork> {
     /// The ECDSA private key
@@ -51,28 +183,30 @@ impl<N: ZcashNetwork> P2PKHSpendingKey<N> {
     pub fn is_compressed(&self) -> bool {
         self.compressed
     }
+
+    /// Returns the WIF of the private key under the given version byte.
+    /// The caller is responsible for choosing a prefix that is valid on the target chain.
+    pub fn to_wif_for_prefix(&self, prefix: u8) -> String {
+        let mut wif = [0u8; 38];
+        wif[0] = prefix;
+        wif[1..33].copy_from_slice(&self.secret_key[..]);
+
+        if self.compressed {
+            wif[33] = 0x01;
+            let sum = ZcashPrivateKey::<N>::wif_checksum(&wif[0..34]);
+            wif[34..].copy_from_slice(&sum);
+            wif.to_base58()
+        } else {
+            let sum = ZcashPrivateKey::<N>::wif_checksum(&wif[0..33]);
+            wif[33..37].copy_from_slice(&sum);
+            wif[..37].to_base58()
+        }
+    }
 }
 
 impl<N: ZcashNetwork> Display for P2PKHSpendingKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        /// Returns a WIF string given a secp256k1 secret key.
-        fn to_wif<N: ZcashNetwork>(secret_key: &secp256k1::SecretKey, compressed: bool) -> String {
-            let mut wif = [0u8; 38];
-            wif[0] = N::to_wif_prefix();
-            wif[1..33].copy_from_slice(&secret_key[..]);
-
-            if compressed {
-                wif[33] = 0x01;
-                let sum = &checksum(&wif[0..34])[0..4];
-                wif[34..].copy_from_slice(sum);
-                wif.to_base58()
-            } else {
-                let sum = &checksum(&wif[0..33])[0..4];
-                wif[33..37].copy_from_slice(sum);
-                wif[..37].to_base58()
-            }
-        }
-        write!(f, "{}", to_wif::<N>(&self.secret_key, self.compressed))
+        write!(f, "{}", self.to_wif_for_prefix(N::to_wif_prefix()))
     }
 }
 
@@ -268,15 +402,199 @@ impl<N: ZcashNetwork> PrivateKey for ZcashPrivateKey<N> {
 }
 
 impl<N: ZcashNetwork> ZcashPrivateKey<N> {
-    /// Returns a randomly-generated Zcash P2PKH private key.
+    /// Returns a randomly-generated Zcash P2PKH private key in the default compression of the network.
     pub fn new_p2pkh<R: Rng>(rng: &mut R) -> Result<Self, PrivateKeyError> {
+        Self::new_with_policy(rng, CompressionPolicy::for_network::<N>())
+    }
+
+    /// Returns a randomly-generated Zcash P2PKH private key in the form of the given compression policy.
+    pub fn new_with_policy<R: Rng>(rng: &mut R, policy: CompressionPolicy) -> Result<Self, PrivateKeyError> {
         let random: [u8; 32] = rng.gen();
         let secret_key = secp256k1::SecretKey::from_slice(&random)?;
         Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
-            secret_key, true,
+            secret_key,
+            policy == CompressionPolicy::Compressed,
+        )))
+    }
+
+    /// Returns a randomly-generated Zcash P2PKH private key in the form of the given compression policy,
+    /// and a record of the random number generator and time of its generation.
+    pub fn generate_audited<R: Rng>(
+        rng: &mut R,
+        policy: CompressionPolicy,
+    ) -> Result<(Self, AuditRecord), PrivateKeyError> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| PrivateKeyError::Message(error.to_string()))?
+            .as_secs();
+        let record = AuditRecord {
+            rng: type_name::<R>().into(),
+            timestamp,
+            network: N::NAME.into(),
+            compressed: policy == CompressionPolicy::Compressed,
+        };
+        Ok((Self::new_with_policy(rng, policy)?, record))
+    }
+
+    /// Returns the Zcash P2PKH private key of a given secp256k1 secret key in the given compression.
+    /// The compression is never inferred, as a secret key is 32 bytes in either form.
+    pub fn from_secret_key_with_compression(secret_key: secp256k1::SecretKey, compressed: bool) -> Self {
+        ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, compressed))
+    }
+
+    /// Returns the Zcash P2PKH private key of a given secp256k1 keypair in the given compression,
+    /// failing if the public key is not the public key of the secret key.
+    pub fn from_keypair(
+        secret_key: secp256k1::SecretKey,
+        public_key: secp256k1::PublicKey,
+        compressed: bool,
+    ) -> Result<Self, PrivateKeyError> {
+        if secp256k1::PublicKey::from_secret_key(&SECP256K1, &secret_key) != public_key {
+            return Err(PrivateKeyError::KeypairMismatch);
+        }
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secret_key, compressed,
         )))
     }
 
+    /// Returns the Zcash P2PKH private key of the smallest secret key, 1, in the default compression
+    /// of the network, for testing and diagnosing the boundaries of encodings.
+    pub fn min_scalar() -> Result<Self, PrivateKeyError> {
+        let mut secret_key = [0u8; 32];
+        secret_key[31] = 1;
+        Self::from_boundary_scalar(&secret_key)
+    }
+
+    /// Returns the Zcash P2PKH private key of the largest secret key, the order of the curve less 1,
+    /// in the default compression of the network, for testing and diagnosing the boundaries of encodings.
+    pub fn max_scalar() -> Result<Self, PrivateKeyError> {
+        Self::from_boundary_scalar(&MAX_SECRET_KEY)
+    }
+
+    /// Returns the Zcash P2PKH private key of the given secret key in the default compression of the network.
+    fn from_boundary_scalar(secret_key: &[u8; 32]) -> Result<Self, PrivateKeyError> {
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secp256k1::SecretKey::from_slice(secret_key)?,
+            N::default_compression(),
+        )))
+    }
+
+    /// Returns the Zcash P2PKH private keys of the given hex encodings of 32-byte secret keys in the
+    /// given compression. Each entry is validated independently, so an invalid entry fails alone.
+    pub fn from_hex_list(hexes: &[&str], compressed: bool) -> Vec<Result<Self, PrivateKeyError>> {
+        hexes
+            .iter()
+            .map(|secret_key| {
+                let secret_key = hex::decode(secret_key)?;
+                if secret_key.len() != 32 {
+                    return Err(PrivateKeyError::InvalidByteLength(secret_key.len()));
+                }
+                Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+                    secp256k1::SecretKey::from_slice(&secret_key)?,
+                    compressed,
+                )))
+            })
+            .collect()
+    }
+
+    /// Returns the compressed Zcash P2PKH private key at the given index of a master secret,
+    /// derived deterministically as HKDF-SHA256 of the master with the big-endian index as its info.
+    /// This is not BIP32 derivation; keys derived this way are not recoverable from a mnemonic.
+    pub fn derive_counter(master: &[u8; 32], index: u64) -> Result<Self, PrivateKeyError> {
+        let mut prk = [0u8; 32];
+        hkdf_extract(Sha256::new(), DERIVE_COUNTER_SALT, master, &mut prk);
+        let mut okm = [0u8; 32];
+        hkdf_expand(Sha256::new(), &prk, &index.to_be_bytes(), &mut okm);
+
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secp256k1::SecretKey::from_slice(&okm)?,
+            true,
+        )))
+    }
+
+    /// Returns the uncompressed Zcash P2PKH private key of a given mini private key, the 22 or 30
+    /// character base58 string beginning with 'S' used by paper wallets, whose secret key is its SHA256.
+    /// A mini private key is well-formed if the SHA256 of the string followed by '?' begins with a zero byte.
+    pub fn from_minikey(minikey: &str) -> Result<Self, PrivateKeyError> {
+        if minikey.len() != 22 && minikey.len() != 30 {
+            return Err(PrivateKeyError::InvalidCharacterLength(minikey.len()));
+        }
+        if !minikey.starts_with('S') {
+            return Err(PrivateKeyError::InvalidPrefix(minikey.as_bytes()[0..1].to_vec()));
+        }
+        minikey.from_base58()?;
+
+        let mut check = [0u8; 32];
+        let mut sha256 = Sha256::new();
+        sha256.input_str(minikey);
+        sha256.input_str("?");
+        sha256.result(&mut check);
+        if check[0] != 0 {
+            return Err(PrivateKeyError::Message(format!(
+                "invalid mini private key: {}",
+                minikey
+            )));
+        }
+
+        let mut secret_key = [0u8; 32];
+        let mut sha256 = Sha256::new();
+        sha256.input_str(minikey);
+        sha256.result(&mut secret_key);
+
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secp256k1::SecretKey::from_slice(&secret_key)?,
+            false,
+        )))
+    }
+
+    /// Returns the compressed Zcash P2PKH private key of a given passphrase, whose secret key is the SHA256
+    /// of the NFKD normalization of the passphrase, so that every Unicode encoding of it yields the same key.
+    pub fn from_passphrase(passphrase: &str) -> Result<Self, PrivateKeyError> {
+        let mut secret_key = [0u8; 32];
+        let mut sha256 = Sha256::new();
+        sha256.input_str(&nfkd(passphrase));
+        sha256.result(&mut secret_key);
+
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secp256k1::SecretKey::from_slice(&secret_key)?,
+            true,
+        )))
+    }
+
+    /// Returns a compressed P2PKH private key derived from the given passphrase with scrypt of the given
+    /// cost. Unlike `from_passphrase`, each guess of the passphrase costs an attacker a tunable amount of
+    /// time and memory. The passphrase is NFKD-normalized, so that its forms derive the same key.
+    pub fn from_passphrase_with_scrypt(passphrase: &str, params: &ScryptParams) -> Result<Self, PrivateKeyError> {
+        let mut secret_key = [0u8; 32];
+        scrypt(
+            nfkd(passphrase).as_bytes(),
+            PASSPHRASE_SCRYPT_SALT,
+            &params.to_scrypt()?,
+            &mut secret_key,
+        );
+
+        Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            secp256k1::SecretKey::from_slice(&secret_key)?,
+            true,
+        )))
+    }
+
+    /// Returns a randomly-generated Zcash P2PKH private key in the form of the given compression policy,
+    /// whose public key has an even y-coordinate (a compressed public key prefix of 0x02).
+    /// Half of all secret keys qualify, so generation takes 2 attempts on average.
+    pub fn new_even_y<R: Rng>(rng: &mut R, policy: CompressionPolicy) -> Result<Self, PrivateKeyError> {
+        loop {
+            let random: [u8; 32] = rng.gen();
+            let secret_key = secp256k1::SecretKey::from_slice(&random)?;
+            if secp256k1::PublicKey::from_secret_key(&SECP256K1, &secret_key).serialize()[0] == 0x02 {
+                return Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+                    secret_key,
+                    policy == CompressionPolicy::Compressed,
+                )));
+            }
+        }
+    }
+
     /// Returns a randomly-generated Zcash Sprout private key.
     pub fn new_sprout<R: Rng>(rng: &mut R) -> Result<Self, PrivateKeyError> {
         let spending_key = SproutSpendingKey::<N>::new(rng.gen());
@@ -288,25 +606,291 @@ impl<N: ZcashNetwork> ZcashPrivateKey<N> {
         Self::sapling(&rng.gen())
     }
 
-    /// Returns a P2PKH private key from a given WIF.
-    fn p2pkh(wif: &str) -> Result<Self, PrivateKeyError> {
+    /// Returns the WIF of the P2PKH private key under the given version byte, for interoperability with
+    /// other chains that share the secp256k1 key format. The caller is responsible for choosing a valid prefix.
+    pub fn to_wif_for_prefix(&self, prefix: u8) -> Result<String, PrivateKeyError> {
+        match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => Ok(spending_key.to_wif_for_prefix(prefix)),
+            _ => Err(PrivateKeyError::UnsupportedFormat),
+        }
+    }
+
+    /// Returns the byte layout of the WIF of the P2PKH private key, for educational output.
+    pub fn wif_breakdown(&self) -> Result<WifLayout, PrivateKeyError> {
+        let spending_key = match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key,
+            _ => return Err(PrivateKeyError::UnsupportedFormat),
+        };
+
+        let mut payload = vec![N::to_wif_prefix()];
+        payload.extend(&spending_key.secret_key[..]);
+        if spending_key.compressed {
+            payload.push(0x01);
+        }
+
+        Ok(WifLayout {
+            version: hex::encode(&payload[0..1]),
+            secret_key: hex::encode(&payload[1..33]),
+            compression_flag: payload.get(33).map(|flag| hex::encode([*flag])),
+            checksum: hex::encode(Self::wif_checksum(&payload)),
+        })
+    }
+
+    /// Returns the paper wallet of the WIFs and addresses of the P2PKH secret key in both compressed
+    /// and uncompressed form, as they are different keys and addresses for the same secret.
+    pub fn to_paper_wallet(&self) -> Result<PaperWallet<N>, PrivateKeyError> {
+        let secret_key = match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key.to_secp256k1_secret_key(),
+            _ => return Err(PrivateKeyError::UnsupportedFormat),
+        };
+
+        let compressed = ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, true));
+        let uncompressed = ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, false));
+        let to_address = |private_key: &Self| {
+            private_key
+                .to_address(&ZcashFormat::P2PKH)
+                .map_err(|error| PrivateKeyError::Message(error.to_string()))
+        };
+
+        Ok(PaperWallet {
+            compressed_wif: compressed.to_string(),
+            compressed_address: to_address(&compressed)?,
+            uncompressed_wif: uncompressed.to_string(),
+            uncompressed_address: to_address(&uncompressed)?,
+        })
+    }
+
+    /// Returns the encoded private key with all but its first and last four characters masked,
+    /// e.g. `L***…***VH4g`, for confirming a key without disclosing it.
+    pub fn wif_masked(&self) -> String {
+        let encoded = self.to_string();
+        let head = encoded.chars().take(1).collect::<String>();
+        let tail = encoded
+            .chars()
+            .skip(encoded.chars().count().saturating_sub(4))
+            .collect::<String>();
+        format!("{}***…***{}", head, tail)
+    }
+
+    /// Returns the 4-byte checksum of a WIF payload (the prefix, secret key, and optional
+    /// compression flag), computed as the first four bytes of SHA256(SHA256(payload)).
+    pub fn wif_checksum(payload: &[u8]) -> [u8; 4] {
+        let mut sum = [0u8; 4];
+        sum.copy_from_slice(&checksum(payload)[0..4]);
+        sum
+    }
+
+    /// Returns the expected first character of the encoded private key. The version byte alone fixes
+    /// the first character of most WIFs, but a compressed mainnet WIF begins with `K` or `L`
+    /// depending on the magnitude of the secret key, so it is computed from the WIF payload.
+    pub fn expected_wif_first_char(&self) -> char {
+        match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                let mut wif = vec![N::to_wif_prefix()];
+                wif.extend(&spending_key.secret_key[..]);
+                if spending_key.compressed {
+                    wif.push(0x01);
+                }
+                let sum = Self::wif_checksum(&wif);
+                wif.extend(&sum);
+
+                // Divide the big-endian payload by 58 until only its leading base58 digit remains.
+                // The version byte is nonzero, so the WIF has no leading zero digits.
+                while wif.len() > 1 || wif[0] >= 58 {
+                    let mut remainder = 0u32;
+                    wif = wif
+                        .iter()
+                        .map(|byte| {
+                            let value = remainder * 256 + u32::from(*byte);
+                            remainder = value % 58;
+                            (value / 58) as u8
+                        })
+                        .skip_while(|digit| *digit == 0)
+                        .collect();
+                }
+                BASE58_ALPHABET[wif[0] as usize] as char
+            }
+            _ => self.to_string().chars().next().unwrap_or_default(),
+        }
+    }
+
+    /// Returns a rough estimate of the entropy in bits of a passphrase used to derive a private key.
+    /// This is a heuristic, not a measurement: each character is assumed to be drawn uniformly from
+    /// the union of the character classes (lowercase, uppercase, digits, symbols, non-ASCII) present,
+    /// and repeated characters beyond the first occurrence contribute a single bit each.
+    pub fn estimate_entropy_bits(source: &str) -> f64 {
+        let mut pool = 0u32;
+        if source.chars().any(|c| c.is_ascii_lowercase()) {
+            pool += 26;
+        }
+        if source.chars().any(|c| c.is_ascii_uppercase()) {
+            pool += 26;
+        }
+        if source.chars().any(|c| c.is_ascii_digit()) {
+            pool += 10;
+        }
+        if source.chars().any(|c| c.is_ascii() && !c.is_ascii_alphanumeric()) {
+            pool += 33;
+        }
+        if !source.is_ascii() {
+            pool += 100;
+        }
+        if pool == 0 {
+            return 0.0;
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let (unique, repeated) = source
+            .chars()
+            .fold((0u32, 0u32), |(unique, repeated), c| match seen.insert(c) {
+                true => (unique + 1, repeated),
+                false => (unique, repeated + 1),
+            });
+
+        f64::from(unique) * f64::from(pool).log2() + f64::from(repeated)
+    }
+
+    /// Returns a P2PKH private key from a given WIF, and `true` if the WIF is in compressed form.
+    pub fn from_wif_detect(wif: &str) -> Result<(Self, bool), PrivateKeyError> {
+        let private_key = Self::p2pkh(wif)?;
+        let compressed = match &private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key.is_compressed(),
+            _ => return Err(PrivateKeyError::UnsupportedFormat),
+        };
+        Ok((private_key, compressed))
+    }
+
+    /// Returns a P2PKH private key from a given WIF, rejecting legacy uncompressed WIFs
+    /// and WIFs of another network.
+    pub fn from_wif_strict(wif: &str) -> Result<Self, PrivateKeyError> {
+        match Self::from_wif_detect(wif)? {
+            (private_key, true) => Ok(private_key),
+            (_, false) => Err(PrivateKeyError::LegacyUncompressedRejected),
+        }
+    }
+
+    /// Returns the canonical form of a given WIF, with whitespace and URL percent-encoding removed.
+    /// Non-ASCII input, such as a unicode lookalike of a base58 character, is rejected,
+    /// as is a WIF of another network, which is never re-encoded as a WIF of network `N`.
+    pub fn canonicalize_wif(input: &str) -> Result<String, PrivateKeyError> {
+        if let Some(character) = input.chars().find(|c| !c.is_ascii()) {
+            return Err(PrivateKeyError::Message(format!(
+                "invalid non-ASCII character: {:?}",
+                character
+            )));
+        }
+
+        let mut wif = String::with_capacity(input.len());
+        let mut bytes = input.bytes();
+        while let Some(byte) = bytes.next() {
+            let byte = match byte {
+                b'%' => {
+                    let hex: String = bytes.by_ref().take(2).map(char::from).collect();
+                    match u8::from_str_radix(&hex, 16) {
+                        Ok(byte) if hex.len() == 2 && byte.is_ascii() => byte,
+                        _ => return Err(PrivateKeyError::Message(format!("invalid percent-encoding: %{}", hex))),
+                    }
+                }
+                byte => byte,
+            };
+            if !byte.is_ascii_whitespace() {
+                wif.push(char::from(byte));
+            }
+        }
+
+        Ok(Self::p2pkh(&wif)?.to_string())
+    }
+
+    /// Returns the private key of the first WIF in a pasted line, such as `label: "L1aW4..."`, and the
+    /// label preceding it, if any. The WIF is the first run of base58 characters of the length of
+    /// an uncompressed (51) or compressed (52) WIF, and the label is the text before it, without
+    /// surrounding whitespace, quotes, or a trailing `:` or `=`.
+    pub fn from_labeled_line(line: &str) -> Result<(Self, Option<String>), PrivateKeyError> {
+        let is_base58 = |c: char| c.is_ascii() && BASE58_ALPHABET.contains(&(c as u8));
+
+        let mut start = 0;
+        while start < line.len() {
+            let end = match line[start..].find(|c: char| !is_base58(c)) {
+                Some(offset) => start + offset,
+                None => line.len(),
+            };
+
+            if end - start == 51 || end - start == 52 {
+                let label = line[..start]
+                    .trim_end_matches(|c: char| c.is_whitespace() || ['"', '\'', ':', '='].contains(&c))
+                    .trim_start_matches(|c: char| c.is_whitespace() || ['"', '\''].contains(&c));
+                let label = match label.is_empty() {
+                    true => None,
+                    false => Some(label.to_string()),
+                };
+                return Ok((Self::from_str(&line[start..end])?, label));
+            }
+
+            // Skip the run of base58 characters, then the following non-base58 character.
+            start = match line[end..].chars().next() {
+                Some(c) => end + c.len_utf8(),
+                None => end,
+            };
+        }
+
+        Err(PrivateKeyError::Message("no WIF found in line".into()))
+    }
+
+    /// Returns the prefix byte, secret key bytes, and compression of a given WIF, verifying its
+    /// length, checksum, and compression flag without constructing a private key.
+    pub fn decode_wif_parts(wif: &str) -> Result<(u8, [u8; 32], bool), PrivateKeyError> {
+        // Reject any payload that is not an uncompressed (37 byte) or compressed (38 byte) WIF
+        // before slicing it for the checksum and secret key.
         let data = wif.from_base58()?;
         let len = data.len();
+        if len == 32 {
+            return Err(PrivateKeyError::LooksLikeRawKeyNotWif);
+        }
         if len != 37 && len != 38 {
-            return Err(PrivateKeyError::InvalidCharacterLength(len));
+            return Err(PrivateKeyError::InvalidByteLength(len));
         }
 
-        let expected = &data[len - 4..][0..4];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
+        // Reject any encoding that does not round-trip, such as one padded with extra leading zeros.
+        if data.to_base58() != wif {
+            return Err(PrivateKeyError::Message("non-canonical base58 encoding".into()));
         }
 
+        let mut actual = [0u8; 4];
+        actual.copy_from_slice(&data[len - 4..]);
+        let expected = Self::wif_checksum(&data[0..len - 4]);
+        if actual != expected {
+            return Err(PrivateKeyError::InvalidChecksum { expected, actual });
+        }
+
+        if len == 38 && data[33] != 0x01 {
+            return Err(PrivateKeyError::Message(format!(
+                "invalid compression flag: {:#04x}",
+                data[33]
+            )));
+        }
+
+        let mut secret_key = [0u8; 32];
+        secret_key.copy_from_slice(&data[1..33]);
+        Ok((data[0], secret_key, len == 38))
+    }
+
+    /// Returns `true` if the given WIFs encode the same secret key, regardless of their compression.
+    /// The secret keys are compared in constant time.
+    pub fn same_secret(a: &str, b: &str) -> Result<bool, PrivateKeyError> {
+        let (_, a, _) = Self::decode_wif_parts(a)?;
+        let (_, b, _) = Self::decode_wif_parts(b)?;
+        Ok(ct_eq(&a, &b))
+    }
+
+    /// Returns a P2PKH private key from a given WIF.
+    /// The WIF must be of network `N`, so that a WIF of another network is never
+    /// returned as, or re-encoded as, a private key of network `N`.
+    fn p2pkh(wif: &str) -> Result<Self, PrivateKeyError> {
+        let (prefix, secret_key, compressed) = Self::decode_wif_parts(wif)?;
+        N::from_wif_prefix(prefix)?;
         Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
-            secp256k1::SecretKey::from_slice(&data[1..33])?,
-            len == 38,
+            secp256k1::SecretKey::from_slice(&secret_key)?,
+            compressed,
         )))
     }
 
@@ -318,12 +902,12 @@ impl<N: ZcashNetwork> ZcashPrivateKey<N> {
             return Err(PrivateKeyError::InvalidByteLength(len));
         }
 
-        let expected = &data[len - 4..][0..4];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
+        let mut actual = [0u8; 4];
+        actual.copy_from_slice(&data[len - 4..]);
+        let mut expected = [0u8; 4];
+        expected.copy_from_slice(&checksum(&data[0..len - 4])[0..4]);
+        if actual != expected {
+            return Err(PrivateKeyError::InvalidChecksum { expected, actual });
         }
 
         let mut sk = [0u8; 32];
@@ -340,6 +924,146 @@ impl<N: ZcashNetwork> ZcashPrivateKey<N> {
         ))
     }
 
+    /// Returns the address of each of the given formats, deriving the public key once for all of them
+    /// instead of once per format as repeated calls to `to_address` would.
+    pub fn to_all_addresses(&self, formats: &[ZcashFormat]) -> Result<Vec<ZcashAddress<N>>, AddressError> {
+        let public_key = self.to_public_key();
+        formats
+            .iter()
+            .map(|format| self.address_of_public_key(&public_key, format))
+            .collect()
+    }
+
+    /// Returns the address of the given format of the private key, in its compression and network.
+    /// A transparent private key yields a P2PKH address, or the P2SH address of a pay-to-pubkey
+    /// redeem script of its public key. Shielded formats are incompatible with a transparent key,
+    /// and a shielded key yields only addresses of its own format.
+    pub fn address_for_format(&self, format: &ZcashFormat) -> Result<ZcashAddress<N>, AddressError> {
+        self.address_of_public_key(&self.to_public_key(), format)
+    }
+
+    /// Returns the address of the given format of the public key of the private key,
+    /// or `IncompatibleFormats` if the format is not an address format of the private key.
+    fn address_of_public_key(
+        &self,
+        public_key: &ZcashPublicKey<N>,
+        format: &ZcashFormat,
+    ) -> Result<ZcashAddress<N>, AddressError> {
+        let (key_format, compatible) = match self {
+            ZcashPrivateKey::<N>::P2PKH(_) => (
+                ZcashFormat::P2PKH,
+                matches!(format, ZcashFormat::P2PKH | ZcashFormat::P2SH),
+            ),
+            ZcashPrivateKey::<N>::P2SH(_) => (ZcashFormat::P2SH, false),
+            ZcashPrivateKey::<N>::Sprout(_) => (ZcashFormat::Sprout, *format == ZcashFormat::Sprout),
+            ZcashPrivateKey::<N>::Sapling(_) => (ZcashFormat::Sapling(None), matches!(format, ZcashFormat::Sapling(_))),
+        };
+        if !compatible {
+            return Err(AddressError::IncompatibleFormats(
+                key_format.to_string(),
+                format.to_string(),
+            ));
+        }
+
+        match format {
+            ZcashFormat::P2SH => ZcashAddress::<N>::p2sh_p2pk(public_key),
+            _ => ZcashAddress::<N>::from_public_key(public_key, format),
+        }
+    }
+
+    /// Returns the Bitcoin address of the given format of the secp256k1 secret key of the private key,
+    /// in its compression, on Bitcoin mainnet for a mainnet key and Bitcoin testnet otherwise.
+    #[cfg(feature = "bitcoin")]
+    pub fn to_bitcoin_address(&self, format: &wagyu_bitcoin::BitcoinFormat) -> Result<String, AddressError> {
+        use wagyu_bitcoin::{BitcoinPrivateKey, Mainnet as BitcoinMainnet, Testnet as BitcoinTestnet};
+
+        let (secret_key, compressed) = match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                (spending_key.to_secp256k1_secret_key(), spending_key.is_compressed())
+            }
+            _ => {
+                return Err(AddressError::IncompatibleFormats(
+                    "shielded private key".into(),
+                    format.to_string(),
+                ))
+            }
+        };
+
+        Ok(match N::IS_MAINNET {
+            true => BitcoinPrivateKey::<BitcoinMainnet>::from_secp256k1_secret_key(secret_key, compressed)
+                .to_address(format)?
+                .to_string(),
+            false => BitcoinPrivateKey::<BitcoinTestnet>::from_secp256k1_secret_key(secret_key, compressed)
+                .to_address(format)?
+                .to_string(),
+        })
+    }
+
+    /// Returns the wallet software that imports the private key, by its kind, network, and compression.
+    /// This is a static mapping for import instructions, not a guarantee of any given wallet version.
+    pub fn compatibility(&self) -> Vec<&'static str> {
+        match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => match (N::IS_MAINNET, spending_key.is_compressed()) {
+                (true, true) => vec!["zcashd", "Zecwallet Fullnode", "Zecwallet Lite", "Ywallet"],
+                (true, false) => vec!["zcashd", "Zecwallet Fullnode"],
+                (false, _) => vec!["zcashd"],
+            },
+            ZcashPrivateKey::<N>::P2SH(_) => vec![],
+            ZcashPrivateKey::<N>::Sprout(_) => vec!["zcashd"],
+            ZcashPrivateKey::<N>::Sapling(_) => match N::IS_MAINNET {
+                true => vec!["zcashd", "Zecwallet Fullnode", "Zecwallet Lite", "Ywallet"],
+                false => vec!["zcashd"],
+            },
+        }
+    }
+
+    /// Returns the signature of the given message, signed with the P2PKH spending key.
+    /// The signing nonce is derived deterministically from the key and message (RFC6979),
+    /// so signing does not depend on a random number generator and is reproducible.
+    pub fn sign_message(&self, message: &[u8]) -> Result<secp256k1::Signature, PrivateKeyError> {
+        self.sign(&to_message_hash(message))
+    }
+
+    /// Returns the DER-encoded signature of the given 32-byte hash, signed with the P2PKH spending key.
+    /// Unlike `sign_message`, the hash is signed as is, such as a transaction signature hash.
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Result<Vec<u8>, PrivateKeyError> {
+        Ok(ZcashSignature::from(self.sign(hash)?).to_der())
+    }
+
+    /// Returns the signature of the given 32-byte hash, signed with the P2PKH spending key,
+    /// with its s value normalized to the lower half of the curve order (BIP62).
+    fn sign(&self, hash: &[u8; 32]) -> Result<secp256k1::Signature, PrivateKeyError> {
+        self.sign_with_context(hash, &*SECP256K1)
+    }
+
+    /// Returns the low-s signature of the given 32-byte hash, signed with the P2PKH spending key
+    /// in the given secp256k1 context.
+    fn sign_with_context<C: secp256k1::Signing>(
+        &self,
+        hash: &[u8; 32],
+        context: &secp256k1::Secp256k1<C>,
+    ) -> Result<secp256k1::Signature, PrivateKeyError> {
+        match self {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                let message = secp256k1::Message::from_slice(hash)?;
+                let mut signature = ZcashSignature::from(context.sign(&message, &spending_key.secret_key));
+                signature.normalize_s();
+                Ok(signature.to_secp256k1_signature())
+            }
+            _ => Err(PrivateKeyError::UnsupportedFormat),
+        }
+    }
+
+    /// Returns the signature of the given message, signed with the P2PKH spending key in the given
+    /// secp256k1 context. The context must have the signing capability, which is checked at compile time.
+    pub fn sign_message_with_context<C: secp256k1::Signing>(
+        &self,
+        message: &[u8],
+        context: &secp256k1::Secp256k1<C>,
+    ) -> Result<secp256k1::Signature, PrivateKeyError> {
+        self.sign_with_context(&to_message_hash(message), context)
+    }
+
     /// Returns a Sapling private key from a given expanded spending key.
     fn sapling_expanded(expanded_spending_key: &str) -> Result<Self, PrivateKeyError> {
         let data = hex::decode(expanded_spending_key)?;
@@ -360,6 +1084,10 @@ impl<N: ZcashNetwork> FromStr for ZcashPrivateKey<N> {
 
         if b58.is_ok() && hex.is_err() && b32.is_err() {
             let data = b58?;
+            // A raw secret key pasted in place of a WIF has no version byte and checksum to verify.
+            if data.len() == 32 {
+                return Err(PrivateKeyError::LooksLikeRawKeyNotWif);
+            }
             if data.len() != 37 && data.len() != 38 {
                 return Err(PrivateKeyError::InvalidByteLength(data.len()));
             }
@@ -475,6 +1203,45 @@ mod tests {
         assert!(ZcashPrivateKey::<N>::from_str(&s).is_err())
     }
 
+    fn test_invalid_compression_flag<N: ZcashNetwork>(wif: &str) {
+        let mut data = wif.from_base58().unwrap();
+        assert_eq!(38, data.len());
+        data[33] = 0x02;
+        let checksum = ZcashPrivateKey::<N>::wif_checksum(&data[0..34]);
+        data[34..].copy_from_slice(&checksum);
+
+        assert!(ZcashPrivateKey::<N>::from_str(&data.to_base58()).is_err())
+    }
+
+    fn test_from_wif_detect<N: ZcashNetwork>(wif: &str, expected_compressed: bool) {
+        let (private_key, compressed) = ZcashPrivateKey::<N>::from_wif_detect(wif).unwrap();
+        assert_eq!(expected_compressed, compressed);
+        assert_eq!(ZcashPrivateKey::<N>::from_str(wif).unwrap(), private_key);
+        assert_eq!(wif, private_key.to_string());
+    }
+
+    fn test_wif_checksum<N: ZcashNetwork>(wif: &str) {
+        let data = wif.from_base58().unwrap();
+        let len = data.len();
+        assert_eq!(
+            &data[len - 4..],
+            &ZcashPrivateKey::<N>::wif_checksum(&data[0..len - 4])[..]
+        );
+
+        let private_key = ZcashPrivateKey::<N>::from_str(wif).unwrap();
+        assert_eq!(wif.chars().next(), Some(private_key.expected_wif_first_char()));
+    }
+
+    fn test_sign_message<N: ZcashNetwork>(private_key: &ZcashPrivateKey<N>) {
+        let message = b"Deterministic signatures do not depend on an RNG";
+        let signature = private_key.sign_message(message).unwrap();
+        assert_eq!(signature, private_key.sign_message(message).unwrap());
+        assert!(private_key.to_public_key().verify_message(message, &signature));
+        assert!(!private_key
+            .to_public_key()
+            .verify_message(b"another message", &signature));
+    }
+
     mod p2pkh_mainnet_compressed {
         use super::*;
 
@@ -563,6 +1330,35 @@ mod tests {
                 test_invalid_checksum::<N>(private_key);
             });
         }
+
+        #[test]
+        fn invalid_compression_flag() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_invalid_compression_flag::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn from_wif_detect() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_from_wif_detect::<N>(private_key, true);
+            });
+        }
+
+        #[test]
+        fn wif_checksum() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_wif_checksum::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn sign_message() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_sign_message(&private_key);
+            });
+        }
     }
 
     mod p2pkh_mainnet_uncompressed {
@@ -653,6 +1449,28 @@ mod tests {
                 test_invalid_checksum::<N>(private_key);
             });
         }
+
+        #[test]
+        fn from_wif_detect() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_from_wif_detect::<N>(private_key, false);
+            });
+        }
+
+        #[test]
+        fn wif_checksum() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_wif_checksum::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn sign_message() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_sign_message(&private_key);
+            });
+        }
     }
 
     mod p2pkh_testnet_compressed {
@@ -743,6 +1561,35 @@ mod tests {
                 test_invalid_checksum::<N>(private_key);
             });
         }
+
+        #[test]
+        fn invalid_compression_flag() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_invalid_compression_flag::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn from_wif_detect() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_from_wif_detect::<N>(private_key, true);
+            });
+        }
+
+        #[test]
+        fn wif_checksum() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_wif_checksum::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn sign_message() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_sign_message(&private_key);
+            });
+        }
     }
 
     mod p2pkh_testnet_uncompressed {
@@ -833,6 +1680,28 @@ mod tests {
                 test_invalid_checksum::<N>(private_key);
             });
         }
+
+        #[test]
+        fn from_wif_detect() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_from_wif_detect::<N>(private_key, false);
+            });
+        }
+
+        #[test]
+        fn wif_checksum() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                test_wif_checksum::<N>(private_key);
+            });
+        }
+
+        #[test]
+        fn sign_message() {
+            KEYPAIRS.iter().for_each(|(private_key, _, _)| {
+                let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_sign_message(&private_key);
+            });
+        }
     }
 
     mod sprout_mainnet {
@@ -1090,4 +1959,997 @@ mod tests {
             });
         }
     }
+
+    #[test]
+    fn sign_hash() {
+        type N = Mainnet;
+
+        let hash = [0x2a; 32];
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let signature = private_key.sign_hash(&hash).unwrap();
+        assert_eq!(0x30, signature[0]);
+        assert_eq!(signature, private_key.sign_hash(&hash).unwrap());
+
+        let public_key = match private_key.to_public_key() {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => viewing_key.public_key,
+            _ => panic!("expected a p2pkh public key"),
+        };
+        let signature = secp256k1::Signature::from_der(&signature).unwrap();
+        let message = secp256k1::Message::from_slice(&hash).unwrap();
+        assert!(SECP256K1.verify(&message, &signature, &public_key).is_ok());
+        let message = secp256k1::Message::from_slice(&[0x2b; 32]).unwrap();
+        assert!(SECP256K1.verify(&message, &signature, &public_key).is_err());
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut").unwrap();
+        assert!(private_key.sign_hash(&hash).is_err());
+    }
+
+    #[test]
+    fn low_s() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        (1..16).for_each(|i| {
+            let private_key = ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap();
+            let signature = ZcashSignature::from_der(&private_key.sign_hash(&[i; 32]).unwrap()).unwrap();
+            assert!(signature.is_low_s());
+            let signature = ZcashSignature::from(private_key.sign_message(&[i]).unwrap());
+            assert!(signature.is_low_s());
+        });
+    }
+
+    #[test]
+    fn non_canonical_wif() {
+        type N = Mainnet;
+
+        // A leading '1' decodes to an extra leading zero byte, shifting the payload.
+        let wif = "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ";
+        assert!(ZcashPrivateKey::<N>::from_str(wif).is_ok());
+        let padded = format!("1{}", wif);
+        assert_eq!(38, padded.from_base58().unwrap().len());
+        assert!(ZcashPrivateKey::<N>::from_str(&padded).is_err());
+        assert!(ZcashPrivateKey::<N>::decode_wif_parts(&padded).is_err());
+
+        // A zero-padded payload with a valid checksum is rejected as well.
+        let mut data = vec![0u8];
+        data.extend(&wif.from_base58().unwrap()[0..33]);
+        data.extend(&ZcashPrivateKey::<N>::wif_checksum(&data));
+        assert!(ZcashPrivateKey::<N>::decode_wif_parts(&data.to_base58()).is_err());
+    }
+
+    #[test]
+    fn new_even_y() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        [CompressionPolicy::Compressed, CompressionPolicy::Uncompressed]
+            .iter()
+            .for_each(|policy| {
+                (0..16).for_each(|_| {
+                    let private_key = ZcashPrivateKey::<N>::new_even_y(rng, *policy).unwrap();
+                    match &private_key {
+                        ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                            let public_key = secp256k1::PublicKey::from_secret_key(
+                                &SECP256K1,
+                                &spending_key.to_secp256k1_secret_key(),
+                            );
+                            assert_eq!(0x02, public_key.serialize()[0]);
+                            assert_eq!(*policy == CompressionPolicy::Compressed, spending_key.is_compressed());
+                        }
+                        _ => panic!("expected a p2pkh private key"),
+                    };
+                    assert!(private_key.to_public_key().to_hex(true).unwrap().starts_with("02"));
+                });
+            });
+    }
+
+    #[test]
+    fn same_secret() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        let compressed = ZcashPrivateKey::<N>::new_with_policy(rng, CompressionPolicy::Compressed).unwrap();
+        let uncompressed = match &compressed {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+                spending_key.to_secp256k1_secret_key(),
+                false,
+            )),
+            _ => panic!("expected a p2pkh private key"),
+        };
+        let (compressed, uncompressed) = (compressed.to_string(), uncompressed.to_string());
+        assert_ne!(compressed, uncompressed);
+        assert!(ZcashPrivateKey::<N>::same_secret(&compressed, &uncompressed).unwrap());
+        assert!(ZcashPrivateKey::<N>::same_secret(&compressed, &compressed).unwrap());
+
+        let other = ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap().to_string();
+        assert!(!ZcashPrivateKey::<N>::same_secret(&compressed, &other).unwrap());
+        assert!(ZcashPrivateKey::<N>::same_secret(&compressed, &other[1..]).is_err());
+    }
+
+    #[test]
+    fn decode_wif_parts() {
+        fn test_decode_wif_parts<N: ZcashNetwork>(wif: &str, expected_prefix: u8) {
+            let (prefix, secret_key, compressed) = ZcashPrivateKey::<N>::decode_wif_parts(wif).unwrap();
+            assert_eq!(expected_prefix, prefix);
+
+            match ZcashPrivateKey::<N>::from_str(wif).unwrap() {
+                ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                    assert_eq!(&spending_key.to_secp256k1_secret_key()[..], &secret_key[..]);
+                    assert_eq!(spending_key.is_compressed(), compressed);
+                }
+                _ => panic!("expected a p2pkh private key"),
+            };
+        }
+
+        test_decode_wif_parts::<Mainnet>("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g", 0x80);
+        test_decode_wif_parts::<Mainnet>("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ", 0x80);
+        test_decode_wif_parts::<Testnet>("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M", 0xef);
+        test_decode_wif_parts::<Testnet>("92VN8AQdnRoBRw7QQcpUYsh1bSEWUrkZ364fjMUdNrm8fTJhEGw", 0xef);
+
+        assert!(
+            ZcashPrivateKey::<Mainnet>::decode_wif_parts("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4h")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn derive_counter() {
+        type N = Mainnet;
+
+        let mut master = [0u8; 32];
+        master.iter_mut().enumerate().for_each(|(i, byte)| *byte = i as u8);
+
+        let first = ZcashPrivateKey::<N>::derive_counter(&master, 0).unwrap();
+        let second = ZcashPrivateKey::<N>::derive_counter(&master, 1).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first, ZcashPrivateKey::<N>::derive_counter(&master, 0).unwrap());
+        assert_eq!(second, ZcashPrivateKey::<N>::derive_counter(&master, 1).unwrap());
+
+        assert_eq!(
+            "t1HyAFXbVmQHgn7C9WqkYijk6hkVoXU2EbA",
+            first.to_address(&ZcashFormat::P2PKH).unwrap().to_string()
+        );
+        assert_eq!(
+            "t1KqQL3StE1fRVWMtpPGYMir592iQM3ULxV",
+            second.to_address(&ZcashFormat::P2PKH).unwrap().to_string()
+        );
+        assert_ne!(first, ZcashPrivateKey::<N>::derive_counter(&[0u8; 32], 0).unwrap());
+    }
+
+    #[test]
+    fn wif_masked() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let masked = ZcashPrivateKey::<N>::from_str(wif).unwrap().wif_masked();
+        assert_eq!("L***…***VH4g", masked);
+        assert!(!masked.contains(&wif[1..wif.len() - 4]));
+
+        let masked = ZcashPrivateKey::<N>::from_str("SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut")
+            .unwrap()
+            .wif_masked();
+        assert_eq!("S***…***i8ut", masked);
+    }
+
+    #[test]
+    fn sign_message_with_context() {
+        type N = Mainnet;
+
+        let message = b"wagyu";
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let signature = private_key.sign_message(message).unwrap();
+
+        let context = secp256k1::Secp256k1::signing_only();
+        assert_eq!(
+            signature,
+            private_key.sign_message_with_context(message, &context).unwrap()
+        );
+        assert_eq!(
+            signature,
+            private_key.sign_message_with_context(message, &*SECP256K1).unwrap()
+        );
+        assert!(private_key.to_public_key().verify_message(message, &signature));
+
+        let sapling = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling.sign_message_with_context(message, &context).is_err());
+    }
+
+    #[test]
+    fn to_wif_for_prefix() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let private_key = ZcashPrivateKey::<N>::from_str(wif).unwrap();
+        let secret_key = match &private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key.to_secp256k1_secret_key(),
+            _ => panic!("expected a p2pkh private key"),
+        };
+
+        // Bitcoin mainnet shares the 0x80 version byte with Zcash mainnet.
+        assert_eq!(wif, private_key.to_wif_for_prefix(0x80).unwrap());
+
+        // Litecoin mainnet uses the 0xb0 version byte.
+        let litecoin = private_key.to_wif_for_prefix(0xb0).unwrap();
+        assert!(litecoin.starts_with('T'));
+        let data = litecoin.from_base58().unwrap();
+        assert_eq!(38, data.len());
+        assert_eq!(0xb0, data[0]);
+        assert_eq!(&secret_key[..], &data[1..33]);
+        assert_eq!(0x01, data[33]);
+        assert_eq!(ZcashPrivateKey::<N>::wif_checksum(&data[0..34]), data[34..]);
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(private_key.to_wif_for_prefix(0x80).is_err());
+    }
+
+    #[test]
+    fn new_with_policy() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        let is_compressed = |private_key: &ZcashPrivateKey<N>| match private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => spending_key.is_compressed(),
+            _ => panic!("expected a p2pkh private key"),
+        };
+
+        let private_key = ZcashPrivateKey::<N>::new_with_policy(rng, CompressionPolicy::Uncompressed).unwrap();
+        assert!(!is_compressed(&private_key));
+        assert!(private_key.to_string().starts_with('5'));
+
+        let private_key = ZcashPrivateKey::<N>::new_with_policy(rng, CompressionPolicy::Compressed).unwrap();
+        assert!(is_compressed(&private_key));
+
+        // The default policy and `new` remain compressed.
+        assert_eq!(CompressionPolicy::Compressed, CompressionPolicy::default());
+        assert!(is_compressed(&ZcashPrivateKey::<N>::new(rng).unwrap()));
+    }
+
+    #[test]
+    fn short_wif() {
+        type N = Mainnet;
+
+        let wif = [0x80, 0x01, 0x02].to_base58();
+        let results = [
+            ZcashPrivateKey::<N>::from_str(&wif),
+            ZcashPrivateKey::<N>::from_wif_detect(&wif).map(|(private_key, _)| private_key),
+            ZcashPrivateKey::<N>::from_wif_strict(&wif),
+        ];
+        results.iter().for_each(|result| match result {
+            Err(PrivateKeyError::InvalidByteLength(3)) => (),
+            result => panic!("expected an invalid byte length error, found {:?}", result),
+        });
+    }
+
+    #[test]
+    fn to_all_addresses() {
+        type N = Mainnet;
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        let formats: Vec<ZcashFormat> = (0..3)
+            .map(|_| {
+                let address = private_key.to_address(&ZcashFormat::Sapling(None)).unwrap();
+                ZcashFormat::Sapling(Some(ZcashAddress::<N>::get_diversifier(&address.to_string()).unwrap()))
+            })
+            .collect();
+
+        let addresses = private_key.to_all_addresses(&formats).unwrap();
+        assert_eq!(formats.len(), addresses.len());
+        formats.iter().zip(&addresses).for_each(|(format, address)| {
+            assert_eq!(private_key.to_address(format).unwrap(), *address);
+        });
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        assert_eq!(
+            vec![private_key.to_address(&ZcashFormat::P2PKH).unwrap()],
+            private_key.to_all_addresses(&[ZcashFormat::P2PKH]).unwrap()
+        );
+        assert!(private_key.to_all_addresses(&[]).unwrap().is_empty());
+
+        // Each format yields the same address as `address_for_format`, and an incompatible format fails.
+        let formats = [ZcashFormat::P2PKH, ZcashFormat::P2SH];
+        assert_eq!(
+            formats
+                .iter()
+                .map(|format| private_key.address_for_format(format).unwrap())
+                .collect::<Vec<_>>(),
+            private_key.to_all_addresses(&formats).unwrap()
+        );
+        assert!(private_key.to_all_addresses(&[ZcashFormat::P2SH]).unwrap()[0]
+            .to_string()
+            .starts_with("t3"));
+        assert!(matches!(
+            private_key.to_all_addresses(&[ZcashFormat::P2PKH, ZcashFormat::Sapling(None)]),
+            Err(AddressError::IncompatibleFormats(_, _))
+        ));
+    }
+
+    // Compares the time of the cached derivation of `to_all_addresses` with independent derivation
+    // for a batch of formats. Run with `cargo test bench_to_all_addresses -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_to_all_addresses() {
+        use std::time::Instant;
+
+        type N = Mainnet;
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        let formats = vec![ZcashFormat::Sapling(None); 64];
+        // Warm up the lazily-initialized Jubjub parameters, so neither timing includes them.
+        private_key.to_all_addresses(&formats[..1]).unwrap();
+
+        let start = Instant::now();
+        let cached = private_key.to_all_addresses(&formats).unwrap();
+        let cached_time = start.elapsed();
+
+        let start = Instant::now();
+        let independent = formats
+            .iter()
+            .map(|format| private_key.to_address(format).unwrap())
+            .collect::<Vec<_>>();
+        let independent_time = start.elapsed();
+
+        assert_eq!(cached.len(), independent.len());
+        println!(
+            "{} addresses: {:?} cached, {:?} independent",
+            formats.len(),
+            cached_time,
+            independent_time
+        );
+    }
+
+    #[test]
+    fn from_wif_strict() {
+        let compressed = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        assert_eq!(
+            ZcashPrivateKey::<Mainnet>::from_str(compressed).unwrap(),
+            ZcashPrivateKey::<Mainnet>::from_wif_strict(compressed).unwrap()
+        );
+        assert!(
+            ZcashPrivateKey::<Testnet>::from_wif_strict("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M").is_ok()
+        );
+
+        let uncompressed = "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ";
+        match ZcashPrivateKey::<Mainnet>::from_wif_strict(uncompressed) {
+            Err(PrivateKeyError::LegacyUncompressedRejected) => (),
+            result => panic!("expected a legacy uncompressed error, found {:?}", result),
+        };
+        assert!(ZcashPrivateKey::<Mainnet>::from_str(uncompressed).is_ok());
+        assert!(
+            ZcashPrivateKey::<Testnet>::from_wif_strict("92VN8AQdnRoBRw7QQcpUYsh1bSEWUrkZ364fjMUdNrm8fTJhEGw").is_err()
+        );
+
+        // A compressed WIF of another network is not a strict WIF of this network.
+        assert!(matches!(
+            ZcashPrivateKey::<Mainnet>::from_wif_strict("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M"),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(ZcashPrivateKey::<Testnet>::from_wif_strict(compressed).is_err());
+    }
+
+    #[test]
+    fn canonicalize_wif() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        assert_eq!(wif, ZcashPrivateKey::<N>::canonicalize_wif(wif).unwrap());
+        assert_eq!(
+            wif,
+            ZcashPrivateKey::<N>::canonicalize_wif(&format!(" \t{}\r\n", wif)).unwrap()
+        );
+        assert_eq!(
+            wif,
+            ZcashPrivateKey::<N>::canonicalize_wif(&format!("%20{}%0A", wif)).unwrap()
+        );
+
+        // The Cyrillic 'а' (U+0430) is a homoglyph of the base58 character 'a'
+        assert!(ZcashPrivateKey::<N>::canonicalize_wif(&wif.replace('a', "\u{430}")).is_err());
+        assert!(ZcashPrivateKey::<N>::canonicalize_wif(&format!("{}%2", wif)).is_err());
+        assert!(ZcashPrivateKey::<N>::canonicalize_wif(&wif[1..]).is_err());
+
+        // A WIF of another network is rejected rather than re-encoded as a WIF of this network.
+        let testnet = "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M";
+        assert!(matches!(
+            ZcashPrivateKey::<N>::canonicalize_wif(testnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(ZcashPrivateKey::<Testnet>::canonicalize_wif(&format!(" {} ", wif)).is_err());
+        assert_eq!(testnet, ZcashPrivateKey::<Testnet>::canonicalize_wif(testnet).unwrap());
+    }
+
+    #[test]
+    fn estimate_entropy_bits() {
+        type N = Mainnet;
+
+        assert_eq!(0.0, ZcashPrivateKey::<N>::estimate_entropy_bits(""));
+
+        let weak = ZcashPrivateKey::<N>::estimate_entropy_bits("password");
+        assert!(weak < 40.0);
+        assert!(ZcashPrivateKey::<N>::estimate_entropy_bits("aaaaaaaaaaaaaaaa") < weak);
+
+        let strong = ZcashPrivateKey::<N>::estimate_entropy_bits("Tr0ub4dor&3-correct-Horse-battery-staple!");
+        assert!(strong > 128.0);
+    }
+
+    #[test]
+    fn from_wif_detect_network() {
+        let mainnet = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let testnet = "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M";
+
+        // A WIF of another network is rejected rather than returned as a key of this network.
+        assert!(matches!(
+            ZcashPrivateKey::<Mainnet>::from_wif_detect(testnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(matches!(
+            ZcashPrivateKey::<Testnet>::from_wif_detect(mainnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(
+            ZcashPrivateKey::<Mainnet>::from_wif_detect("92VN8AQdnRoBRw7QQcpUYsh1bSEWUrkZ364fjMUdNrm8fTJhEGw").is_err()
+        );
+        assert!(ZcashPrivateKey::<Regtest>::from_wif_detect(testnet).is_ok());
+    }
+
+    #[test]
+    fn address_for_format() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let p2pkh = private_key.address_for_format(&ZcashFormat::P2PKH).unwrap();
+        let p2sh = private_key.address_for_format(&ZcashFormat::P2SH).unwrap();
+        assert_eq!("t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod", p2pkh.to_string());
+        assert!(p2sh.to_string().starts_with("t3"));
+        assert_ne!(p2pkh, p2sh);
+        assert_eq!(
+            p2sh,
+            ZcashAddress::<N>::p2sh_p2pk(&private_key.to_public_key()).unwrap()
+        );
+
+        // The P2SH address wraps the public key in the compression of the private key.
+        let (_, secret_key, _) = ZcashPrivateKey::<N>::decode_wif_parts(&private_key.to_string()).unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&secret_key).unwrap();
+        let uncompressed = ZcashPrivateKey::<N>::from_secret_key_with_compression(secret_key, false);
+        assert_ne!(p2sh, uncompressed.address_for_format(&ZcashFormat::P2SH).unwrap());
+
+        // A testnet key yields addresses of the testnet.
+        let testnet =
+            ZcashPrivateKey::<Testnet>::from_str("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M").unwrap();
+        assert!(testnet
+            .address_for_format(&ZcashFormat::P2SH)
+            .unwrap()
+            .to_string()
+            .starts_with("t2"));
+
+        assert!(private_key.address_for_format(&ZcashFormat::Sprout).is_err());
+        assert!(private_key.address_for_format(&ZcashFormat::Sapling(None)).is_err());
+
+        let sapling = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling.address_for_format(&ZcashFormat::Sapling(None)).is_ok());
+        assert!(sapling.address_for_format(&ZcashFormat::P2PKH).is_err());
+    }
+
+    #[cfg(feature = "bitcoin")]
+    #[test]
+    fn to_bitcoin_address() {
+        use wagyu_bitcoin::BitcoinFormat;
+
+        // The secret key 1, whose Bitcoin addresses are well known
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn").unwrap();
+        assert_eq!(
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            private_key.to_bitcoin_address(&BitcoinFormat::P2PKH).unwrap()
+        );
+        assert_eq!(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            private_key.to_bitcoin_address(&BitcoinFormat::Bech32).unwrap()
+        );
+
+        let (_, secret_key, _) = ZcashPrivateKey::<Mainnet>::decode_wif_parts(&private_key.to_string()).unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&secret_key).unwrap();
+        let uncompressed = ZcashPrivateKey::<Mainnet>::from_secret_key_with_compression(secret_key, false);
+        assert_eq!(
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm",
+            uncompressed.to_bitcoin_address(&BitcoinFormat::P2PKH).unwrap()
+        );
+
+        let sapling = ZcashPrivateKey::<Mainnet>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling.to_bitcoin_address(&BitcoinFormat::P2PKH).is_err());
+    }
+
+    #[test]
+    fn looks_like_raw_key_not_wif() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let (_, secret_key, _) = ZcashPrivateKey::<N>::decode_wif_parts(wif).unwrap();
+        let raw = secret_key.to_base58();
+
+        assert!(matches!(
+            ZcashPrivateKey::<N>::from_str(&raw),
+            Err(PrivateKeyError::LooksLikeRawKeyNotWif)
+        ));
+        assert!(matches!(
+            ZcashPrivateKey::<N>::decode_wif_parts(&raw),
+            Err(PrivateKeyError::LooksLikeRawKeyNotWif)
+        ));
+
+        // A WIF of another length is still rejected by its length.
+        assert!(matches!(
+            ZcashPrivateKey::<N>::from_str(&wif[..wif.len() - 4]),
+            Err(PrivateKeyError::InvalidByteLength(_))
+        ));
+    }
+
+    #[test]
+    fn from_labeled_line() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let expected = ZcashPrivateKey::<N>::from_str(wif).unwrap();
+
+        let (private_key, label) = ZcashPrivateKey::<N>::from_labeled_line(&format!("savings: \"{}\"", wif)).unwrap();
+        assert_eq!(expected, private_key);
+        assert_eq!(Some("savings".to_string()), label);
+
+        let (private_key, label) =
+            ZcashPrivateKey::<N>::from_labeled_line(&format!("'cold wallet' = '{}',", wif)).unwrap();
+        assert_eq!(expected, private_key);
+        assert_eq!(Some("cold wallet".to_string()), label);
+
+        let (private_key, label) = ZcashPrivateKey::<N>::from_labeled_line(&format!("  {}  ", wif)).unwrap();
+        assert_eq!(expected, private_key);
+        assert_eq!(None, label);
+
+        // A label of base58 characters shorter than a WIF is not taken for the WIF.
+        let (_, label) = ZcashPrivateKey::<N>::from_labeled_line(&format!("key1 {}", wif)).unwrap();
+        assert_eq!(Some("key1".to_string()), label);
+
+        assert!(ZcashPrivateKey::<N>::from_labeled_line("savings: none").is_err());
+        assert!(ZcashPrivateKey::<N>::from_labeled_line(&format!("savings: \"{}\"", &wif[..51])).is_err());
+    }
+
+    #[test]
+    fn compatibility() {
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let wallets = private_key.compatibility();
+        ["zcashd", "Zecwallet Lite", "Ywallet"]
+            .iter()
+            .for_each(|wallet| assert!(wallets.contains(wallet)));
+
+        // Light wallets import only compressed mainnet keys.
+        let uncompressed =
+            ZcashPrivateKey::<Mainnet>::from_str("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ").unwrap();
+        assert!(!uncompressed.compatibility().contains(&"Zecwallet Lite"));
+        let testnet =
+            ZcashPrivateKey::<Testnet>::from_str("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M").unwrap();
+        assert_eq!(vec!["zcashd"], testnet.compatibility());
+    }
+
+    #[test]
+    fn to_address_network() {
+        let mainnet = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let testnet = "cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M";
+
+        let address = ZcashPrivateKey::<Mainnet>::from_str(mainnet)
+            .unwrap()
+            .to_address(&ZcashFormat::P2PKH)
+            .unwrap();
+        assert_eq!("t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod", address.to_string());
+        assert!(ZcashAddress::<Testnet>::from_str(&address.to_string()).is_err());
+
+        let address = ZcashPrivateKey::<Testnet>::from_str(testnet)
+            .unwrap()
+            .to_address(&ZcashFormat::P2PKH)
+            .unwrap();
+        assert_eq!("tmWT3bvWCHQkAXXucPjWHqLs9EyWUDdzSuN", address.to_string());
+        assert!(ZcashAddress::<Mainnet>::from_str(&address.to_string()).is_err());
+
+        // A private key of one network cannot be read as a private key of the other,
+        // through any of the WIF entry points.
+        assert!(ZcashPrivateKey::<Mainnet>::from_str(testnet).is_err());
+        assert!(ZcashPrivateKey::<Testnet>::from_str(mainnet).is_err());
+        assert!(matches!(
+            ZcashPrivateKey::<Mainnet>::from_wif_detect(testnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(matches!(
+            ZcashPrivateKey::<Testnet>::from_wif_strict(mainnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(matches!(
+            ZcashPrivateKey::<Mainnet>::canonicalize_wif(testnet),
+            Err(PrivateKeyError::InvalidPrefix(_))
+        ));
+        assert!(crate::address::wif_to_address::<Mainnet>(testnet, &ZcashFormat::P2PKH).is_err());
+        assert!(crate::address::wif_to_address::<Testnet>(mainnet, &ZcashFormat::P2PKH).is_err());
+
+        let sapling = "secret-spending-key-test1fygsm6l3ltqqs63040tq426p85pgzuetdeg6q0wka38nx3rfa70sa9qp0v";
+        assert!(ZcashPrivateKey::<Mainnet>::from_str(sapling).is_err());
+        let address = ZcashPrivateKey::<Testnet>::from_str(sapling)
+            .unwrap()
+            .to_address(&ZcashFormat::Sapling(None))
+            .unwrap();
+        assert!(address.to_string().starts_with("ztestsapling"));
+        assert!(ZcashAddress::<Mainnet>::from_str(&address.to_string()).is_err());
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        type N = Mainnet;
+
+        let corrupted = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4h";
+        let data = corrupted.from_base58().unwrap();
+        match ZcashPrivateKey::<N>::from_str(corrupted) {
+            Err(PrivateKeyError::InvalidChecksum { expected, actual }) => {
+                assert_ne!(expected, actual);
+                assert_eq!(ZcashPrivateKey::<N>::wif_checksum(&data[0..34]), expected);
+                assert_eq!(&data[34..38], &actual[..]);
+
+                let message = PrivateKeyError::InvalidChecksum { expected, actual }.to_string();
+                assert!(message.contains(&format!("{:02x?}", expected)));
+                assert!(message.contains(&format!("{:02x?}", actual)));
+            }
+            result => panic!("expected an invalid checksum, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn from_minikey() {
+        type N = Mainnet;
+
+        let private_key = ZcashPrivateKey::<N>::from_minikey("S6c56bnXQiBjk9mqSYE7ykVQ7NzrRy").unwrap();
+        match &private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                assert_eq!(
+                    hex!("4c7a9640c72dc2099f23715d0c8a0d8a35f8906e3cab61dd3f78b67bf887c9ab"),
+                    spending_key.to_secp256k1_secret_key()[..]
+                );
+                assert!(!spending_key.is_compressed());
+            }
+            _ => panic!("expected a P2PKH private key"),
+        }
+        assert!(ZcashPrivateKey::<N>::from_minikey("SzavMBLoXU6kDrqtUVmffv").is_ok());
+
+        // Invalid check byte, length, prefix, and character
+        assert!(ZcashPrivateKey::<N>::from_minikey("S6c56bnXQiBjk9mqSYE7ykVQ7NzrRz").is_err());
+        assert!(ZcashPrivateKey::<N>::from_minikey("S6c56bnXQiBjk9mqSYE7ykVQ7NzrR").is_err());
+        assert!(ZcashPrivateKey::<N>::from_minikey("T6c56bnXQiBjk9mqSYE7ykVQ7NzrRy").is_err());
+        assert!(ZcashPrivateKey::<N>::from_minikey("S6c56bnXQiBjk9mqSYE7ykVQ7NzrR0").is_err());
+    }
+
+    #[test]
+    fn from_passphrase() {
+        type N = Mainnet;
+
+        let private_key = ZcashPrivateKey::<N>::from_passphrase("correct horse battery staple").unwrap();
+        assert_eq!(
+            "t1Uzbdo5vjKZSbgLZ6TjVttYDo5WBfnZoyb",
+            private_key.to_address(&ZcashFormat::P2PKH).unwrap().to_string()
+        );
+
+        // Precomposed and decomposed forms of the same passphrase
+        let composed = ZcashPrivateKey::<N>::from_passphrase("caf\u{e9} cr\u{e8}me br\u{fb}l\u{e9}e").unwrap();
+        let decomposed =
+            ZcashPrivateKey::<N>::from_passphrase("cafe\u{301} cre\u{300}me bru\u{302}le\u{301}e").unwrap();
+        assert_eq!(composed, decomposed);
+        assert_ne!(
+            composed,
+            ZcashPrivateKey::<N>::from_passphrase("cafe creme brulee").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_passphrase_with_scrypt() {
+        type N = Mainnet;
+
+        let passphrase = "correct horse battery staple";
+        let weaker = ScryptParams { n: 1024, r: 8, p: 1 };
+        let stronger = ScryptParams { n: 32768, r: 8, p: 1 };
+        [weaker, ScryptParams::default(), stronger].iter().for_each(|params| {
+            let private_key = ZcashPrivateKey::<N>::from_passphrase_with_scrypt(passphrase, params).unwrap();
+            assert_eq!(
+                private_key,
+                ZcashPrivateKey::<N>::from_passphrase_with_scrypt(passphrase, params).unwrap()
+            );
+            assert_eq!(
+                private_key,
+                ZcashPrivateKey::<N>::from_str(&private_key.to_string()).unwrap()
+            );
+            assert_ne!(private_key, ZcashPrivateKey::<N>::from_passphrase(passphrase).unwrap());
+        });
+
+        // The cost is part of the derivation.
+        assert_ne!(
+            ZcashPrivateKey::<N>::from_passphrase_with_scrypt(passphrase, &weaker).unwrap(),
+            ZcashPrivateKey::<N>::from_passphrase_with_scrypt(passphrase, &stronger).unwrap()
+        );
+
+        [
+            ScryptParams { n: 1000, r: 8, p: 1 },
+            ScryptParams { n: 1, r: 8, p: 1 },
+            ScryptParams { n: 0, r: 8, p: 1 },
+            ScryptParams { n: 1024, r: 0, p: 1 },
+            ScryptParams { n: 1024, r: 8, p: 0 },
+            ScryptParams { n: 1 << 16, r: 1, p: 1 },
+            ScryptParams {
+                n: 1024,
+                r: 1 << 15,
+                p: 1 << 15,
+            },
+        ]
+        .iter()
+        .for_each(|params| assert!(ZcashPrivateKey::<N>::from_passphrase_with_scrypt(passphrase, params).is_err()));
+    }
+
+    #[test]
+    fn generate_audited() {
+        type N = Testnet;
+
+        let rng = &mut rand::thread_rng();
+        let (private_key, record) =
+            ZcashPrivateKey::<N>::generate_audited(rng, CompressionPolicy::Uncompressed).unwrap();
+        assert!(!record.rng.is_empty());
+        assert!(record.rng.contains("ThreadRng"));
+        assert!(record.timestamp > 0);
+        assert_eq!("testnet", record.network);
+        assert!(!record.compressed);
+
+        let secret_key = match &private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => {
+                assert!(!spending_key.is_compressed());
+                hex::encode(&spending_key.to_secp256k1_secret_key()[..])
+            }
+            _ => panic!("expected a P2PKH private key"),
+        };
+        let serialized = format!("{:?} {}", record, serde_json::to_string(&record).unwrap());
+        assert!(!serialized.contains(&secret_key));
+        assert!(!serialized.contains(&private_key.to_string()));
+    }
+
+    #[test]
+    fn to_paper_wallet() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let paper_wallet = private_key.to_paper_wallet().unwrap();
+        assert_eq!(private_key.to_string(), paper_wallet.compressed_wif);
+        assert_eq!(
+            private_key.to_address(&ZcashFormat::P2PKH).unwrap(),
+            paper_wallet.compressed_address
+        );
+
+        // Both WIFs encode the same secret key, and each address is that of its WIF.
+        assert!(
+            ZcashPrivateKey::<N>::same_secret(&paper_wallet.compressed_wif, &paper_wallet.uncompressed_wif).unwrap()
+        );
+        let (_, _, compressed) = ZcashPrivateKey::<N>::decode_wif_parts(&paper_wallet.uncompressed_wif).unwrap();
+        assert!(!compressed);
+        let uncompressed = ZcashPrivateKey::<N>::from_str(&paper_wallet.uncompressed_wif).unwrap();
+        assert_eq!(
+            uncompressed.to_address(&ZcashFormat::P2PKH).unwrap(),
+            paper_wallet.uncompressed_address
+        );
+        assert_ne!(paper_wallet.compressed_address, paper_wallet.uncompressed_address);
+
+        // The paper wallet of either form is the same.
+        assert_eq!(paper_wallet, uncompressed.to_paper_wallet().unwrap());
+
+        let sapling = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling.to_paper_wallet().is_err());
+    }
+
+    #[test]
+    fn expected_wif_first_char() {
+        type N = Mainnet;
+
+        // The secret key 1 is the smallest secret key, so its compressed WIF begins with K.
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let secret_key = secp256k1::SecretKey::from_slice(&secret).unwrap();
+        let private_key = ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, true));
+        assert_eq!('K', private_key.expected_wif_first_char());
+        assert_eq!(
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn",
+            private_key.to_string()
+        );
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        assert_eq!('L', private_key.expected_wif_first_char());
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ").unwrap();
+        assert_eq!('5', private_key.expected_wif_first_char());
+
+        let private_key =
+            ZcashPrivateKey::<Testnet>::from_str("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M").unwrap();
+        assert_eq!('c', private_key.expected_wif_first_char());
+
+        let rng = &mut rand::thread_rng();
+        (0..16).for_each(|_| {
+            let private_key = ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap();
+            assert_eq!(
+                private_key.to_string().chars().next(),
+                Some(private_key.expected_wif_first_char())
+            );
+        });
+    }
+
+    #[test]
+    fn from_keypair() {
+        type N = Mainnet;
+
+        let rng = &mut rand::thread_rng();
+        let secret_key = secp256k1::SecretKey::from_slice(&rng.gen::<[u8; 32]>()).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&SECP256K1, &secret_key);
+
+        let private_key = ZcashPrivateKey::<N>::from_keypair(secret_key, public_key, true).unwrap();
+        assert_eq!(
+            ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(secret_key, true)),
+            private_key
+        );
+        let uncompressed = ZcashPrivateKey::<N>::from_keypair(secret_key, public_key, false).unwrap();
+        assert_ne!(private_key, uncompressed);
+
+        let other = secp256k1::SecretKey::from_slice(&rng.gen::<[u8; 32]>()).unwrap();
+        let mismatched = secp256k1::PublicKey::from_secret_key(&SECP256K1, &other);
+        assert!(matches!(
+            ZcashPrivateKey::<N>::from_keypair(secret_key, mismatched, true),
+            Err(PrivateKeyError::KeypairMismatch)
+        ));
+    }
+
+    #[test]
+    fn wif_breakdown() {
+        fn test_wif_breakdown<N: ZcashNetwork>(wif: &str, compressed: bool) {
+            let layout = ZcashPrivateKey::<N>::from_str(wif).unwrap().wif_breakdown().unwrap();
+            assert_eq!(hex::encode([N::to_wif_prefix()]), layout.version);
+            assert_eq!(64, layout.secret_key.len());
+            assert_eq!(compressed, layout.compression_flag == Some("01".into()));
+            assert_eq!(!compressed, layout.compression_flag.is_none());
+
+            let reassembled = [
+                layout.version,
+                layout.secret_key,
+                layout.compression_flag.unwrap_or_default(),
+                layout.checksum,
+            ]
+            .concat();
+            assert_eq!(hex::encode(wif.from_base58().unwrap()), reassembled);
+        }
+
+        test_wif_breakdown::<Mainnet>("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g", true);
+        test_wif_breakdown::<Mainnet>("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ", false);
+        test_wif_breakdown::<Testnet>("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M", true);
+
+        let sapling = ZcashPrivateKey::<Mainnet>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling.wif_breakdown().is_err());
+    }
+
+    #[test]
+    fn from_hex_list() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let (_, secret_key, _) = ZcashPrivateKey::<N>::decode_wif_parts(wif).unwrap();
+        let valid = hex::encode(secret_key);
+        let short = &valid[0..62];
+        // The order of the secp256k1 curve, one more than the largest secret key
+        let out_of_range = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        let zero = "0000000000000000000000000000000000000000000000000000000000000000";
+
+        let private_keys = ZcashPrivateKey::<N>::from_hex_list(&[&valid, short, out_of_range, zero, "wagyu"], true);
+        assert_eq!(5, private_keys.len());
+        assert_eq!(wif, private_keys[0].as_ref().unwrap().to_string());
+        assert!(matches!(private_keys[1], Err(PrivateKeyError::InvalidByteLength(31))));
+        assert!(private_keys[2].is_err());
+        assert!(private_keys[3].is_err());
+        assert!(private_keys[4].is_err());
+
+        let private_keys = ZcashPrivateKey::<N>::from_hex_list(&[&valid], false);
+        let uncompressed = private_keys[0].as_ref().unwrap().to_string();
+        assert!(uncompressed.starts_with('5'));
+        assert!(ZcashPrivateKey::<N>::same_secret(wif, &uncompressed).unwrap());
+        assert!(ZcashPrivateKey::<N>::from_hex_list(&[], true).is_empty());
+    }
+
+    #[test]
+    fn min_and_max_scalar() {
+        fn test_round_trip<N: ZcashNetwork>(private_key: &ZcashPrivateKey<N>, expected_secret_key: &[u8; 32]) {
+            let wif = private_key.to_string();
+            assert_eq!(*private_key, ZcashPrivateKey::<N>::from_str(&wif).unwrap());
+            let (_, secret_key, compressed) = ZcashPrivateKey::<N>::decode_wif_parts(&wif).unwrap();
+            assert_eq!(*expected_secret_key, secret_key);
+            assert_eq!(N::default_compression(), compressed);
+
+            let address = private_key.to_address(&ZcashFormat::P2PKH).unwrap();
+            assert_eq!(address, ZcashAddress::<N>::from_str(&address.to_string()).unwrap());
+        }
+
+        fn test_boundaries<N: ZcashNetwork>() {
+            let mut one = [0u8; 32];
+            one[31] = 1;
+            test_round_trip(&ZcashPrivateKey::<N>::min_scalar().unwrap(), &one);
+            test_round_trip(&ZcashPrivateKey::<N>::max_scalar().unwrap(), &MAX_SECRET_KEY);
+        }
+
+        test_boundaries::<Mainnet>();
+        test_boundaries::<Testnet>();
+        test_boundaries::<Regtest>();
+
+        assert_eq!(
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn",
+            ZcashPrivateKey::<Mainnet>::min_scalar().unwrap().to_string()
+        );
+        assert!(ZcashPrivateKey::<Mainnet>::max_scalar()
+            .unwrap()
+            .to_string()
+            .starts_with('L'));
+
+        // One more than the largest secret key is the order of the curve, which is not a secret key.
+        let mut order = MAX_SECRET_KEY;
+        order[31] += 1;
+        assert!(secp256k1::SecretKey::from_slice(&order).is_err());
+    }
+
+    #[test]
+    fn from_secret_key_with_compression() {
+        type N = Mainnet;
+
+        let wif = "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g";
+        let (_, secret_key, _) = ZcashPrivateKey::<N>::decode_wif_parts(wif).unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&secret_key).unwrap();
+
+        let compressed = ZcashPrivateKey::<N>::from_secret_key_with_compression(secret_key, true);
+        assert_eq!(wif, compressed.to_string());
+        assert!(
+            ZcashPrivateKey::<N>::decode_wif_parts(&compressed.to_string())
+                .unwrap()
+                .2
+        );
+
+        let uncompressed = ZcashPrivateKey::<N>::from_secret_key_with_compression(secret_key, false);
+        assert!(uncompressed.to_string().starts_with('5'));
+        assert!(
+            !ZcashPrivateKey::<N>::decode_wif_parts(&uncompressed.to_string())
+                .unwrap()
+                .2
+        );
+        assert_ne!(
+            compressed.to_address(&ZcashFormat::P2PKH).unwrap(),
+            uncompressed.to_address(&ZcashFormat::P2PKH).unwrap()
+        );
+    }
 }
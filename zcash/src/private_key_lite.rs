@@ -0,0 +1,98 @@
+use crate::network::ZcashNetwork;
+use crate::private_key::{P2PKHSpendingKey, ZcashPrivateKey};
+use wagyu_model::PrivateKeyError;
+
+use secp256k1;
+use std::{convert::TryFrom, marker::PhantomData};
+
+/// Represents a Zcash P2PKH private key that holds only its secret key, compression, and network,
+/// and encodes its WIF on demand, so that no WIF string is kept resident in memory.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ZcashPrivateKeyLite<N: ZcashNetwork> {
+    /// The ECDSA private key
+    secret_key: secp256k1::SecretKey,
+    /// If true, the private key is serialized in compressed form
+    compressed: bool,
+    /// The network on which this private key is usable
+    _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> ZcashPrivateKeyLite<N> {
+    /// Returns a private key of the given secret key and compression.
+    pub fn new(secret_key: secp256k1::SecretKey, compressed: bool) -> Self {
+        Self {
+            secret_key,
+            compressed,
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the secp256k1 secret key of the private key.
+    pub fn to_secp256k1_secret_key(&self) -> secp256k1::SecretKey {
+        self.secret_key
+    }
+
+    /// Returns `true` if the private key is in compressed form.
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Returns the WIF of the private key, encoded on each call.
+    pub fn wif(&self) -> String {
+        P2PKHSpendingKey::<N>::new(self.secret_key, self.compressed).to_wif_for_prefix(N::to_wif_prefix())
+    }
+}
+
+impl<N: ZcashNetwork> From<ZcashPrivateKeyLite<N>> for ZcashPrivateKey<N> {
+    fn from(private_key: ZcashPrivateKeyLite<N>) -> Self {
+        ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
+            private_key.secret_key,
+            private_key.compressed,
+        ))
+    }
+}
+
+impl<N: ZcashNetwork> TryFrom<ZcashPrivateKey<N>> for ZcashPrivateKeyLite<N> {
+    type Error = PrivateKeyError;
+
+    fn try_from(private_key: ZcashPrivateKey<N>) -> Result<Self, Self::Error> {
+        match private_key {
+            ZcashPrivateKey::<N>::P2PKH(spending_key) => Ok(Self::new(
+                spending_key.to_secp256k1_secret_key(),
+                spending_key.is_compressed(),
+            )),
+            _ => Err(PrivateKeyError::UnsupportedFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    use std::str::FromStr;
+
+    fn test_wif<N: ZcashNetwork>(wif: &str) {
+        let private_key = ZcashPrivateKey::<N>::from_str(wif).unwrap();
+        let lite = ZcashPrivateKeyLite::<N>::try_from(private_key.clone()).unwrap();
+        assert_eq!(private_key.to_string(), lite.wif());
+        assert_eq!(wif, lite.wif());
+        assert_eq!(private_key, ZcashPrivateKey::<N>::from(lite));
+    }
+
+    #[test]
+    fn wif() {
+        test_wif::<Mainnet>("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g");
+        test_wif::<Mainnet>("5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ");
+        test_wif::<Testnet>("cNG7sM13VvGrhKgepLeEiiQAERXpGB6j5NuRwhh6sLh2skMTQf7M");
+        test_wif::<Testnet>("92VN8AQdnRoBRw7QQcpUYsh1bSEWUrkZ364fjMUdNrm8fTJhEGw");
+    }
+
+    #[test]
+    fn try_from_shielded() {
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut").unwrap();
+        assert!(ZcashPrivateKeyLite::<Mainnet>::try_from(private_key).is_err());
+    }
+}
@@ -8,7 +8,11 @@ use crate::librustzcash::sapling_crypto::{
 use crate::librustzcash::JUBJUB;
 use crate::network::ZcashNetwork;
 use crate::private_key::{SaplingOutgoingViewingKey, SaplingSpendingKey, ZcashPrivateKey};
-use wagyu_model::{crypto::checksum, Address, AddressError, PublicKey, PublicKeyError};
+use crate::signature::{to_message_hash, SECP256K1};
+use wagyu_model::{
+    crypto::{checksum, hash160},
+    Address, AddressError, PublicKey, PublicKeyError,
+};
 
 use base58::{FromBase58, ToBase58};
 use bech32::{Bech32, FromBase32, ToBase32};
@@ -191,14 +195,12 @@ impl<N: ZcashNetwork> PublicKey for ZcashPublicKey<N> {
     type PrivateKey = ZcashPrivateKey<N>;
 
     /// Returns the public key corresponding to the given private key.
+    /// The public key retains none of the secret material of the private key.
     fn from_private_key(private_key: &Self::PrivateKey) -> Self {
         match private_key {
             // Transparent Public Key
             ZcashPrivateKey::<N>::P2PKH(spending_key) => ZcashPublicKey::<N>::P2PKH(P2PKHViewingKey {
-                public_key: secp256k1::PublicKey::from_secret_key(
-                    &secp256k1::Secp256k1::new(),
-                    &spending_key.secret_key,
-                ),
+                public_key: secp256k1::PublicKey::from_secret_key(&SECP256K1, &spending_key.secret_key),
                 compressed: spending_key.compressed,
             }),
             // Transparent Multisignature
@@ -220,6 +222,145 @@ impl<N: ZcashNetwork> PublicKey for ZcashPublicKey<N> {
     }
 }
 
+impl<N: ZcashNetwork> ZcashPublicKey<N> {
+    /// Returns `true` if the given signature of the message is valid for the P2PKH public key.
+    pub fn verify_message(&self, message: &[u8], signature: &secp256k1::Signature) -> bool {
+        match self {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => {
+                match secp256k1::Message::from_slice(&to_message_hash(message)) {
+                    Ok(message) => SECP256K1.verify(&message, signature, &viewing_key.public_key).is_ok(),
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the hex encoding of a P2PKH public key in compressed (33-byte) or uncompressed (65-byte) form,
+    /// or `None` for a shielded public key.
+    pub fn to_hex(&self, compressed: bool) -> Option<String> {
+        match self {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => Some(match compressed {
+                true => hex::encode(&viewing_key.public_key.serialize()[..]),
+                false => hex::encode(&viewing_key.public_key.serialize_uncompressed()[..]),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the hash160, RIPEMD160(SHA256), of a P2PKH public key in its compression,
+    /// the payload of its P2PKH address, or `None` for a shielded public key.
+    pub fn to_hash160(&self) -> Option<[u8; 20]> {
+        match self {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => {
+                let mut hash = [0u8; 20];
+                hash.copy_from_slice(&hash160(&match viewing_key.compressed {
+                    true => viewing_key.public_key.serialize().to_vec(),
+                    false => viewing_key.public_key.serialize_uncompressed().to_vec(),
+                }));
+                Some(hash)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the P2PKH public key of the same point that is serialized in compressed form,
+    /// or `None` for a shielded public key.
+    pub fn to_compressed(&self) -> Option<Self> {
+        self.with_compression(true)
+    }
+
+    /// Returns the P2PKH public key of the same point that is serialized in uncompressed form,
+    /// or `None` for a shielded public key.
+    pub fn to_uncompressed(&self) -> Option<Self> {
+        self.with_compression(false)
+    }
+
+    /// Returns a watch-only output descriptor of the form `pkh(<public key hex>)#<checksum>`
+    /// for a P2PKH public key, with the checksum defined in BIP 380.
+    pub fn to_descriptor(&self, format: &ZcashFormat) -> Result<String, AddressError> {
+        match (self, format) {
+            (ZcashPublicKey::<N>::P2PKH(_), ZcashFormat::P2PKH) => {
+                let descriptor = format!("pkh({})", self);
+                match descriptor_checksum(&descriptor) {
+                    Some(checksum) => Ok(format!("{}#{}", descriptor, checksum)),
+                    None => Err(AddressError::Message(format!("invalid descriptor: {}", descriptor))),
+                }
+            }
+            _ => Err(AddressError::IncompatibleFormats(
+                format.to_string(),
+                "pkh descriptor".into(),
+            )),
+        }
+    }
+
+    /// Returns a P2PKH public key from a serialized secp256k1 public key,
+    /// which is in compressed form if it is 33 bytes and in uncompressed form if it is 65 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        match bytes.len() {
+            33 | 65 => Ok(ZcashPublicKey::<N>::P2PKH(P2PKHViewingKey {
+                public_key: secp256k1::PublicKey::from_slice(bytes)?,
+                compressed: bytes.len() == 33,
+            })),
+            length => Err(PublicKeyError::InvalidByteLength(length)),
+        }
+    }
+
+    fn with_compression(&self, compressed: bool) -> Option<Self> {
+        match self {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => Some(ZcashPublicKey::<N>::P2PKH(P2PKHViewingKey {
+                public_key: viewing_key.public_key,
+                compressed,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the 8-character BIP 380 checksum of an output descriptor, which follows the descriptor
+/// after a '#', or `None` if the descriptor contains a character outside the descriptor character set.
+pub fn descriptor_checksum(descriptor: &str) -> Option<String> {
+    const INPUT_CHARSET: &str =
+        "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+    const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    const GENERATOR: [u64; 5] = [0xf5dee51989, 0xa9fdca3312, 0x1bab10e32d, 0x3706b1677a, 0x644d626ffd];
+
+    fn polymod(checksum: u64, value: u64) -> u64 {
+        let top = checksum >> 35;
+        let mut checksum = ((checksum & 0x7ffffffff) << 5) ^ value;
+        GENERATOR.iter().enumerate().for_each(|(i, generator)| {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        });
+        checksum
+    }
+
+    let mut checksum = 1;
+    let mut classes = vec![];
+    for c in descriptor.chars() {
+        let position = INPUT_CHARSET.find(c)? as u64;
+        checksum = polymod(checksum, position & 31);
+        classes.push(position >> 5);
+        if classes.len() == 3 {
+            checksum = polymod(checksum, classes[0] * 9 + classes[1] * 3 + classes[2]);
+            classes.clear();
+        }
+    }
+    match classes.len() {
+        1 => checksum = polymod(checksum, classes[0]),
+        2 => checksum = polymod(checksum, classes[0] * 3 + classes[1]),
+        _ => (),
+    };
+    checksum = (0..8).fold(checksum, |checksum, _| polymod(checksum, 0)) ^ 1;
+
+    Some(
+        (0..8)
+            .map(|i| CHECKSUM_CHARSET[((checksum >> (5 * (7 - i))) & 31) as usize] as char)
+            .collect(),
+    )
+}
+
 impl<N: ZcashNetwork> FromStr for ZcashPublicKey<N> {
     type Err = PublicKeyError;
 
@@ -308,8 +449,11 @@ impl<N: ZcashNetwork> Display for ZcashPublicKey<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address::ZcashTransparentAddress;
     use crate::network::*;
 
+    use std::convert::TryFrom;
+
     fn test_from_private_key<N: ZcashNetwork>(
         expected_public_key: &ZcashPublicKey<N>,
         private_key: &ZcashPrivateKey<N>,
@@ -343,6 +487,24 @@ mod tests {
         assert_eq!(expected_public_key, public_key.to_string());
     }
 
+    fn test_verify_message<N: ZcashNetwork>(private_key: &str, expected_public_key: &str, compressed: bool) {
+        let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+        let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+        assert_eq!(expected_public_key, public_key.to_string());
+        match &public_key {
+            ZcashPublicKey::<N>::P2PKH(viewing_key) => assert_eq!(compressed, viewing_key.compressed),
+            _ => panic!("expected a p2pkh public key"),
+        };
+
+        let message = b"Signatures verify for every network and compression";
+        let signature = private_key.sign_message(message).unwrap();
+        assert!(public_key.verify_message(message, &signature));
+        assert!(ZcashPublicKey::<N>::from_str(expected_public_key)
+            .unwrap()
+            .verify_message(message, &signature));
+        assert!(!public_key.verify_message(b"A different message", &signature));
+    }
+
     mod p2pkh_mainnet_compressed {
         use super::*;
 
@@ -408,6 +570,13 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn verify_message() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                test_verify_message::<N>(private_key, public_key, true);
+            });
+        }
     }
 
     mod p2pkh_mainnet_uncompressed {
@@ -475,6 +644,13 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn verify_message() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                test_verify_message::<N>(private_key, public_key, false);
+            });
+        }
     }
 
     mod p2pkh_testnet_compressed {
@@ -542,6 +718,13 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn verify_message() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                test_verify_message::<N>(private_key, public_key, true);
+            });
+        }
     }
 
     mod p2pkh_testnet_uncompressed {
@@ -609,6 +792,13 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn verify_message() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                test_verify_message::<N>(private_key, public_key, false);
+            });
+        }
     }
 
     mod sprout_mainnet {
@@ -850,4 +1040,193 @@ mod tests {
         let public_key = "039ed714bf521e96e3f3609b74da898e44d0fb64ba68c62c57852470ffc28e3db5039ed714bf521e96e3f3609b74da898e44d0fb64ba68c62c57852470ffc28e3db5";
         assert!(ZcashPublicKey::<N>::from_str(public_key).is_err());
     }
+
+    #[test]
+    fn test_descriptor_checksum() {
+        assert_eq!(Some("89f8spxm".into()), descriptor_checksum("raw(deadbeef)"));
+        assert_eq!(
+            Some("ml40v0wf".into()),
+            descriptor_checksum("pkh([d34db33f/44'/0'/0']xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL/1/*)")
+        );
+        assert_eq!(None, descriptor_checksum("raw(deadbeef)\u{e9}"));
+    }
+
+    #[test]
+    fn test_to_descriptor() {
+        type N = Mainnet;
+
+        let public_key = "0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949";
+        let descriptor = ZcashPublicKey::<N>::from_str(public_key)
+            .unwrap()
+            .to_descriptor(&ZcashFormat::P2PKH)
+            .unwrap();
+        assert_eq!(
+            "pkh(0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949)#faugeuwu",
+            descriptor
+        );
+
+        let public_key = "0471b47908e7a0cd0e053129cde9a38c54730bc63faf780efc4f9b7c3db4ed1b7db0f877ae0e1959d2353bca05bc405fa1c48e76fec3e99c26e48c95cf112dc7c9";
+        let descriptor = ZcashPublicKey::<N>::from_str(public_key)
+            .unwrap()
+            .to_descriptor(&ZcashFormat::P2PKH)
+            .unwrap();
+        assert_eq!(
+            "pkh(0471b47908e7a0cd0e053129cde9a38c54730bc63faf780efc4f9b7c3db4ed1b7db0f877ae0e1959d2353bca05bc405fa1c48e76fec3e99c26e48c95cf112dc7c9)#uzk7kv23",
+            descriptor
+        );
+
+        let mut parts = descriptor.splitn(2, '#');
+        assert_eq!(
+            descriptor_checksum(parts.next().unwrap()),
+            parts.next().map(String::from)
+        );
+
+        let public_key = ZcashPublicKey::<N>::from_str(public_key).unwrap();
+        assert!(public_key.to_descriptor(&ZcashFormat::Sprout).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        type N = Mainnet;
+
+        let public_keys = [
+            "0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949",
+            "0471b47908e7a0cd0e053129cde9a38c54730bc63faf780efc4f9b7c3db4ed1b7db0f877ae0e1959d2353bca05bc405fa1c48e76fec3e99c26e48c95cf112dc7c9",
+        ];
+        public_keys.iter().for_each(|expected_public_key| {
+            let public_key = ZcashPublicKey::<N>::from_bytes(&hex::decode(expected_public_key).unwrap()).unwrap();
+            assert_eq!(ZcashPublicKey::<N>::from_str(expected_public_key).unwrap(), public_key);
+            assert_eq!(*expected_public_key, public_key.to_string());
+            match public_key {
+                ZcashPublicKey::<N>::P2PKH(public_key) => {
+                    assert_eq!(expected_public_key.len() == 66, public_key.compressed)
+                }
+                _ => panic!("expected a p2pkh public key"),
+            };
+        });
+
+        // Invalid length
+        assert!(ZcashPublicKey::<N>::from_bytes(&[0x02; 32]).is_err());
+        assert!(ZcashPublicKey::<N>::from_bytes(&[]).is_err());
+
+        // Invalid prefix
+        let mut bytes = hex::decode(public_keys[0]).unwrap();
+        bytes[0] = 0x05;
+        assert!(ZcashPublicKey::<N>::from_bytes(&bytes).is_err());
+
+        // Not a point on the curve
+        let mut bytes = [0xff; 33];
+        bytes[0] = 0x02;
+        assert!(ZcashPublicKey::<N>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_no_secret() {
+        type N = Mainnet;
+
+        // The transparent viewing key holds only the public key and its compression flag
+        assert_eq!(
+            std::mem::size_of::<secp256k1::PublicKey>() + std::mem::size_of::<bool>(),
+            std::mem::size_of::<P2PKHViewingKey>()
+        );
+        assert_eq!(64, std::mem::size_of::<SproutViewingKey>());
+
+        let private_keys = [
+            "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g",
+            "5JkYwYTFDzd41Uy3qB8ucvENFzFBYHnZGk7GbFnHTwUaepikxpJ",
+            "SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut",
+        ];
+        private_keys.iter().for_each(|private_key| {
+            let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+            let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+            let debug = format!("{:?}", public_key);
+
+            match (&private_key, &public_key) {
+                (ZcashPrivateKey::<N>::P2PKH(spending_key), ZcashPublicKey::<N>::P2PKH(viewing_key)) => {
+                    let secret = hex::encode(&spending_key.secret_key[..]);
+                    assert!(!debug.contains(&secret));
+                    assert!(!hex::encode(&viewing_key.public_key.serialize_uncompressed()[..]).contains(&secret));
+                }
+                (ZcashPrivateKey::<N>::Sprout(spending_key), ZcashPublicKey::<N>::Sprout(viewing_key)) => {
+                    assert_ne!(spending_key.spending_key, viewing_key.key_a);
+                    assert_ne!(spending_key.spending_key, viewing_key.key_b);
+                    assert!(!debug.contains(&format!("{:?}", spending_key.spending_key)));
+                }
+                _ => panic!("mismatched key formats"),
+            };
+
+            // Cloning the public key duplicates only public data
+            assert_eq!(debug, format!("{:?}", public_key.clone()));
+        });
+    }
+
+    #[test]
+    fn test_to_hex() {
+        type N = Mainnet;
+
+        let compressed = "0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949";
+        let public_key = ZcashPublicKey::<N>::from_str(compressed).unwrap();
+        assert_eq!(Some(compressed.to_string()), public_key.to_hex(true));
+
+        let uncompressed = public_key.to_hex(false).unwrap();
+        assert_eq!(130, uncompressed.len());
+        assert!(uncompressed.starts_with("04"));
+        assert_eq!(&compressed[2..], &uncompressed[2..66]);
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert_eq!(None, ZcashPublicKey::<N>::from_private_key(&private_key).to_hex(false));
+    }
+
+    #[test]
+    fn to_hash160() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+        let address = ZcashTransparentAddress::try_from(public_key.to_address(&ZcashFormat::P2PKH).unwrap()).unwrap();
+        assert_eq!(address.to_hash().unwrap(), public_key.to_hash160().unwrap());
+
+        // The hash honors the compression of the public key.
+        let uncompressed = public_key.to_uncompressed().unwrap();
+        assert_ne!(public_key.to_hash160(), uncompressed.to_hash160());
+        let address = ZcashTransparentAddress::try_from(uncompressed.to_address(&ZcashFormat::P2PKH).unwrap()).unwrap();
+        assert_eq!(address.to_hash().unwrap(), uncompressed.to_hash160().unwrap());
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert_eq!(None, ZcashPublicKey::<N>::from_private_key(&private_key).to_hash160());
+    }
+
+    #[test]
+    fn to_compressed() {
+        type N = Mainnet;
+
+        let compressed = "0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949";
+        let public_key = ZcashPublicKey::<N>::from_str(compressed).unwrap();
+
+        let uncompressed_key = public_key.to_uncompressed().unwrap();
+        let compressed_key = uncompressed_key.to_compressed().unwrap();
+        assert_eq!(public_key, compressed_key);
+
+        let compressed_bytes = hex::decode(compressed_key.to_string()).unwrap();
+        let uncompressed_bytes = hex::decode(uncompressed_key.to_string()).unwrap();
+        assert_ne!(compressed_bytes, uncompressed_bytes);
+        assert_eq!(33, compressed_bytes.len());
+        assert_eq!(65, uncompressed_bytes.len());
+        assert_eq!(compressed_bytes[1..33], uncompressed_bytes[1..33]);
+
+        let private_key = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        let public_key = ZcashPublicKey::<N>::from_private_key(&private_key);
+        assert_eq!(None, public_key.to_compressed());
+        assert_eq!(None, public_key.to_uncompressed());
+    }
 }
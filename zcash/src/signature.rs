@@ -0,0 +1,272 @@
+use wagyu_model::{crypto::checksum, PrivateKeyError};
+
+use secp256k1::{All, Message, PublicKey, Secp256k1, SecretKey};
+
+lazy_static! {
+    /// The secp256k1 context shared by all keys for derivation, signing, and verification.
+    /// The context is immutable after creation, so it is `Send + Sync` and safe to use across threads.
+    pub static ref SECP256K1: Secp256k1<All> = Secp256k1::new();
+}
+
+/// The order of the secp256k1 curve divided by 2, the largest s value of a low-S signature
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x5d, 0x57, 0x6e,
+    0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// The compressed public key of the secret key 1, the generator point of the curve
+const CONTEXT_VECTOR_PUBLIC_KEY: [u8; 33] = [
+    0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b, 0x07, 0x02, 0x9b,
+    0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17, 0x98,
+];
+
+/// The SHA256 digest of "Satoshi Nakamoto"
+const CONTEXT_VECTOR_MESSAGE: [u8; 32] = [
+    0xa0, 0xdc, 0x65, 0xff, 0xca, 0x79, 0x98, 0x73, 0xcb, 0xea, 0x0a, 0xc2, 0x74, 0x01, 0x5b, 0x95, 0x26, 0x50, 0x5d,
+    0xaa, 0xae, 0xd3, 0x85, 0x15, 0x54, 0x25, 0xf7, 0x33, 0x77, 0x04, 0x88, 0x3e,
+];
+
+/// The RFC6979 compact signature of the message by the secret key 1
+const CONTEXT_VECTOR_SIGNATURE: [u8; 64] = [
+    0x93, 0x4b, 0x1e, 0xa1, 0x0a, 0x4b, 0x3c, 0x17, 0x57, 0xe2, 0xb0, 0xc0, 0x17, 0xd0, 0xb6, 0x14, 0x3c, 0xe3, 0xc9,
+    0xa7, 0xe6, 0xa4, 0xa4, 0x98, 0x60, 0xd7, 0xa6, 0xab, 0x21, 0x0e, 0xe3, 0xd8, 0x24, 0x42, 0xce, 0x9d, 0x2b, 0x91,
+    0x60, 0x64, 0x10, 0x80, 0x14, 0x78, 0x3e, 0x92, 0x3e, 0xc3, 0x6b, 0x49, 0x74, 0x3e, 0x2f, 0xfa, 0x1c, 0x44, 0x96,
+    0xf0, 0x1a, 0x51, 0x2a, 0xaf, 0xd9, 0xe5,
+];
+
+/// Returns `true` if the shared secp256k1 context derives, signs, and verifies a fixed test vector
+/// as expected. This is a defensive check against a corrupted build of the context or the library.
+pub fn verify_context() -> bool {
+    let mut secret = [0u8; 32];
+    secret[31] = 1;
+
+    let (secret_key, message) = match (
+        SecretKey::from_slice(&secret),
+        Message::from_slice(&CONTEXT_VECTOR_MESSAGE),
+    ) {
+        (Ok(secret_key), Ok(message)) => (secret_key, message),
+        _ => return false,
+    };
+    let public_key = PublicKey::from_secret_key(&SECP256K1, &secret_key);
+    let signature = SECP256K1.sign(&message, &secret_key);
+
+    public_key.serialize()[..] == CONTEXT_VECTOR_PUBLIC_KEY[..]
+        && signature.serialize_compact()[..] == CONTEXT_VECTOR_SIGNATURE[..]
+        && SECP256K1.verify(&message, &signature, &public_key).is_ok()
+}
+
+/// Represents a secp256k1 signature of a transparent spending key, encodable in the
+/// DER form of Bitcoin scripts or the 64-byte compact form of r and s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZcashSignature(secp256k1::Signature);
+
+impl ZcashSignature {
+    /// Returns the signature of a given DER encoding.
+    pub fn from_der(data: &[u8]) -> Result<Self, PrivateKeyError> {
+        Ok(Self(secp256k1::Signature::from_der(data)?))
+    }
+
+    /// Returns the signature of a given 64-byte compact encoding.
+    pub fn from_compact(data: &[u8]) -> Result<Self, PrivateKeyError> {
+        Ok(Self(secp256k1::Signature::from_compact(data)?))
+    }
+
+    /// Returns the DER encoding of the signature.
+    pub fn to_der(&self) -> Vec<u8> {
+        self.0.serialize_der().to_vec()
+    }
+
+    /// Returns the 64-byte compact encoding of the signature.
+    pub fn to_compact(&self) -> [u8; 64] {
+        self.0.serialize_compact()
+    }
+
+    /// Normalizes the s value of the signature to the lower half of the curve order,
+    /// as required of low-S (BIP62) signatures. A low-S signature is unchanged.
+    pub fn normalize_s(&mut self) {
+        self.0.normalize_s();
+    }
+
+    /// Returns `true` if the s value of the signature is in the lower half of the curve order.
+    pub fn is_low_s(&self) -> bool {
+        self.to_compact()[32..] <= SECP256K1_HALF_ORDER[..]
+    }
+
+    /// Returns the secp256k1 signature.
+    pub fn to_secp256k1_signature(&self) -> secp256k1::Signature {
+        self.0
+    }
+}
+
+impl From<secp256k1::Signature> for ZcashSignature {
+    fn from(signature: secp256k1::Signature) -> Self {
+        Self(signature)
+    }
+}
+
+/// The magic string prepended to a message before it is hashed for signing.
+pub const MESSAGE_MAGIC: &str = "Zcash Signed Message:\n";
+
+/// Returns the hash of a message to be signed by a transparent spending key.
+/// The digest is SHA256(SHA256(len(magic) || magic || len(message) || message)),
+/// where lengths are encoded as compact sizes.
+pub fn to_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut data = vec![];
+    write_compact_size(&mut data, MESSAGE_MAGIC.len() as u64);
+    data.extend(MESSAGE_MAGIC.as_bytes());
+    write_compact_size(&mut data, message.len() as u64);
+    data.extend(message);
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&checksum(&data));
+    hash
+}
+
+/// Appends the compact size encoding of the given length to the buffer.
+fn write_compact_size(buffer: &mut Vec<u8>, length: u64) {
+    match length {
+        0..=0xfc => buffer.push(length as u8),
+        0xfd..=0xffff => {
+            buffer.push(0xfd);
+            buffer.extend(&(length as u16).to_le_bytes());
+        }
+        0x10000..=0xffffffff => {
+            buffer.push(0xfe);
+            buffer.extend(&(length as u32).to_le_bytes());
+        }
+        _ => {
+            buffer.push(0xff);
+            buffer.extend(&length.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::ZcashAddress;
+    use crate::format::ZcashFormat;
+    use crate::network::*;
+    use crate::private_key::ZcashPrivateKey;
+    use crate::public_key::ZcashPublicKey;
+    use wagyu_model::PrivateKey;
+
+    use rand::rngs::StdRng;
+    use rand_core::SeedableRng;
+    use std::{str::FromStr, thread};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn compact_size() {
+        let sizes: [(u64, &str); 4] = [
+            (0x16, "16"),
+            (0xfd, "fdfd00"),
+            (0x10000, "fe00000100"),
+            (0x100000000, "ff0000000001000000"),
+        ];
+
+        sizes.iter().for_each(|(length, expected)| {
+            let mut buffer = vec![];
+            write_compact_size(&mut buffer, *length);
+            assert_eq!(*expected, hex::encode(buffer));
+        });
+    }
+
+    #[test]
+    fn message_hash() {
+        assert_eq!(to_message_hash(b"wagyu"), to_message_hash(b"wagyu"));
+        assert_ne!(to_message_hash(b"wagyu"), to_message_hash(b"Wagyu"));
+        assert_ne!(to_message_hash(b""), [0u8; 32]);
+    }
+
+    #[test]
+    fn signature_encodings() {
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let signature = ZcashSignature::from(private_key.sign_message(b"wagyu").unwrap());
+
+        let der = signature.to_der();
+        assert_eq!(0x30, der[0]);
+        assert_eq!(signature, ZcashSignature::from_der(&der).unwrap());
+
+        let compact = signature.to_compact();
+        assert_eq!(signature, ZcashSignature::from_compact(&compact).unwrap());
+        assert_eq!(der, ZcashSignature::from_compact(&compact).unwrap().to_der());
+
+        assert!(ZcashSignature::from_der(&der[1..]).is_err());
+        assert!(ZcashSignature::from_compact(&compact[1..]).is_err());
+    }
+
+    #[test]
+    fn normalize_s() {
+        /// The order of the secp256k1 curve
+        const ORDER: [u8; 32] = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xba, 0xae,
+            0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+        ];
+
+        let private_key =
+            ZcashPrivateKey::<Mainnet>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let signature = ZcashSignature::from(private_key.sign_message(b"wagyu").unwrap());
+        assert!(signature.is_low_s());
+
+        // Negate s, as n - s, to produce the equivalent high-S signature.
+        let mut compact = signature.to_compact();
+        let mut borrow = 0i16;
+        (0..32).rev().for_each(|i| {
+            let difference = i16::from(ORDER[i]) - i16::from(compact[32 + i]) - borrow;
+            borrow = if difference < 0 { 1 } else { 0 };
+            compact[32 + i] = (difference + 256 * borrow) as u8;
+        });
+
+        let mut high = ZcashSignature::from_compact(&compact).unwrap();
+        assert!(!high.is_low_s());
+        assert_ne!(signature, high);
+        high.normalize_s();
+        assert!(high.is_low_s());
+        assert_eq!(signature, high);
+    }
+
+    #[test]
+    fn test_verify_context() {
+        assert!(verify_context());
+    }
+
+    #[test]
+    fn send_sync() {
+        assert_send_sync::<Secp256k1<All>>();
+        assert_send_sync::<ZcashPrivateKey<Mainnet>>();
+        assert_send_sync::<ZcashPublicKey<Mainnet>>();
+        assert_send_sync::<ZcashAddress<Mainnet>>();
+        assert_send_sync::<ZcashPrivateKey<Testnet>>();
+        assert_send_sync::<ZcashPublicKey<Testnet>>();
+        assert_send_sync::<ZcashAddress<Testnet>>();
+    }
+
+    #[test]
+    fn concurrent_keys() {
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                thread::spawn(move || {
+                    let rng = &mut StdRng::from_entropy();
+                    (0..16)
+                        .map(|_| {
+                            let private_key = ZcashPrivateKey::<Mainnet>::new_p2pkh(rng).unwrap();
+                            let public_key = private_key.to_public_key();
+                            let message = format!("thread {}", i);
+                            let signature = private_key.sign_message(message.as_bytes()).unwrap();
+                            assert!(public_key.verify_message(message.as_bytes(), &signature));
+                            private_key.to_address(&ZcashFormat::P2PKH).unwrap()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut addresses: Vec<_> = threads.into_iter().flat_map(|thread| thread.join().unwrap()).collect();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(8 * 16, addresses.len());
+    }
+}
@@ -0,0 +1,636 @@
+use wagyu_model::{slip39::ENGLISH, MnemonicError};
+
+use crypto::{hmac::Hmac, mac::Mac, pbkdf2::pbkdf2, sha2::Sha256};
+use rand::Rng;
+use std::collections::BTreeMap;
+
+/// The customization string of the checksum and of the encryption of the master secret
+const CUSTOMIZATION: &[u8] = b"shamir";
+/// The number of bits of a word of the wordlist
+const RADIX_BITS: usize = 10;
+/// The number of words of the header, the identifier through the member threshold
+const HEADER_WORDS: usize = 4;
+/// The number of words of the checksum
+const CHECKSUM_WORDS: usize = 3;
+/// The generator of the RS1024 checksum
+const CHECKSUM_GENERATOR: [u32; 10] = [
+    0x00e0_e040,
+    0x01c1_c080,
+    0x0383_8100,
+    0x0707_0200,
+    0x0e0e_0009,
+    0x1c0c_2412,
+    0x3808_6c24,
+    0x3090_fc48,
+    0x21b1_f890,
+    0x03f3_f120,
+];
+
+/// The total number of PBKDF2 iterations of the encryption at iteration exponent 0
+const BASE_ITERATION_COUNT: u32 = 10000;
+/// The iteration exponent of new shares
+const ITERATION_EXPONENT: u8 = 1;
+/// The maximum iteration exponent accepted from a share, bounding the cost of decryption
+const MAX_ITERATION_EXPONENT: u8 = 4;
+/// The number of rounds of the Feistel network encrypting the master secret
+const ROUND_COUNT: u8 = 4;
+
+/// The index of the share of the shared secret
+const SECRET_INDEX: u8 = 255;
+/// The index of the share of the digest of the shared secret
+const DIGEST_INDEX: u8 = 254;
+/// The number of bytes of the digest of the shared secret
+const DIGEST_LENGTH: usize = 4;
+/// The maximum number of groups, and of member shares of a group
+const MAX_SHARE_COUNT: usize = 16;
+/// The minimum number of bytes of a master secret
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// Represents a SLIP-0039 share of a master secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Share {
+    /// The random 15-bit identifier common to all shares of a master secret
+    identifier: u16,
+    /// The exponent of the number of PBKDF2 iterations of the encryption of the master secret
+    iteration_exponent: u8,
+    /// The index of the group of the share
+    group_index: u8,
+    /// The number of groups required to recover the master secret
+    group_threshold: u8,
+    /// The number of groups
+    group_count: u8,
+    /// The index of the share in its group
+    member_index: u8,
+    /// The number of shares of the group required to recover the group secret
+    member_threshold: u8,
+    /// The share value
+    value: Vec<u8>,
+}
+
+impl Share {
+    /// Returns the mnemonic of the share, in the English SLIP-0039 wordlist.
+    fn to_mnemonic(&self) -> String {
+        let header = u64::from(self.identifier) << 25
+            | u64::from(self.iteration_exponent) << 20
+            | u64::from(self.group_index) << 16
+            | u64::from(self.group_threshold - 1) << 12
+            | u64::from(self.group_count - 1) << 8
+            | u64::from(self.member_index) << 4
+            | u64::from(self.member_threshold - 1);
+
+        let mut words: Vec<u32> = (0..HEADER_WORDS)
+            .rev()
+            .map(|i| (header >> (RADIX_BITS * i)) as u32 & 0x3ff)
+            .collect();
+        words.extend(to_words(&self.value));
+        words.extend(create_checksum(&words));
+
+        let wordlist: Vec<&str> = ENGLISH.lines().collect();
+        words
+            .iter()
+            .map(|word| wordlist[*word as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the share of a given mnemonic, verifying its checksum and padding.
+    fn from_mnemonic(mnemonic: &str) -> Result<Self, MnemonicError> {
+        let wordlist: Vec<&str> = ENGLISH.lines().collect();
+        let words = mnemonic
+            .split_whitespace()
+            .map(|word| match wordlist.binary_search(&word.to_lowercase().as_str()) {
+                Ok(index) => Ok(index as u32),
+                Err(_) => Err(MnemonicError::InvalidWord(word.into())),
+            })
+            .collect::<Result<Vec<u32>, MnemonicError>>()?;
+
+        // The share value of a master secret of at least 16 bytes is at least 13 words.
+        let minimum = HEADER_WORDS + (MIN_SECRET_LENGTH * 8).div_ceil(RADIX_BITS) + CHECKSUM_WORDS;
+        if words.len() < minimum {
+            return Err(MnemonicError::InvalidWordCount(words.len().min(u8::MAX as usize) as u8));
+        }
+        if checksum(&words) != 1 {
+            return Err(MnemonicError::InvalidPhrase(mnemonic.into()));
+        }
+
+        let header = words[0..HEADER_WORDS]
+            .iter()
+            .fold(0u64, |header, word| header << RADIX_BITS | u64::from(*word));
+        let share = Self {
+            identifier: (header >> 25) as u16,
+            iteration_exponent: (header >> 20) as u8 & 0x1f,
+            group_index: (header >> 16) as u8 & 0xf,
+            group_threshold: ((header >> 12) as u8 & 0xf) + 1,
+            group_count: ((header >> 8) as u8 & 0xf) + 1,
+            member_index: (header >> 4) as u8 & 0xf,
+            member_threshold: (header as u8 & 0xf) + 1,
+            value: from_words(&words[HEADER_WORDS..words.len() - CHECKSUM_WORDS])
+                .ok_or_else(|| MnemonicError::InvalidPhrase(mnemonic.into()))?,
+        };
+
+        if share.iteration_exponent > MAX_ITERATION_EXPONENT {
+            return Err(MnemonicError::InvalidShares(format!(
+                "iteration exponent {} exceeds {}",
+                share.iteration_exponent, MAX_ITERATION_EXPONENT
+            )));
+        }
+        if share.group_threshold > share.group_count {
+            return Err(MnemonicError::InvalidShares(format!(
+                "group threshold {} exceeds group count {}",
+                share.group_threshold, share.group_count
+            )));
+        }
+        Ok(share)
+    }
+}
+
+/// Returns the SLIP-0039 mnemonic shares of a given master secret, encrypted with the passphrase.
+/// The groups are the (threshold, count) of the member shares of each group, and the threshold is the
+/// number of groups required to recover the master secret. The shares are returned by group.
+pub fn split_slip39<R: Rng>(
+    rng: &mut R,
+    seed: &[u8],
+    groups: &[(u8, u8)],
+    threshold: u8,
+    passphrase: &str,
+) -> Result<Vec<Vec<String>>, MnemonicError> {
+    if seed.len() < MIN_SECRET_LENGTH || !seed.len().is_multiple_of(2) {
+        return Err(MnemonicError::InvalidEntropyLength(seed.len()));
+    }
+    if threshold == 0 || threshold as usize > groups.len() || groups.len() > MAX_SHARE_COUNT {
+        return Err(MnemonicError::InvalidShares(format!(
+            "invalid group threshold {} of {} groups",
+            threshold,
+            groups.len()
+        )));
+    }
+    for (member_threshold, member_count) in groups {
+        if *member_threshold == 0 || member_threshold > member_count || *member_count as usize > MAX_SHARE_COUNT {
+            return Err(MnemonicError::InvalidShares(format!(
+                "invalid member threshold {} of {} shares",
+                member_threshold, member_count
+            )));
+        }
+        // A group of several shares of which any one recovers the group secret is a group of one share.
+        if *member_threshold == 1 && *member_count > 1 {
+            return Err(MnemonicError::InvalidShares(
+                "a member threshold of 1 requires a single share".into(),
+            ));
+        }
+    }
+
+    let identifier = rng.gen::<u16>() & 0x7fff;
+    let encrypted = encrypt(seed, passphrase, ITERATION_EXPONENT, identifier)?;
+    let group_secrets = split_secret(rng, threshold, groups.len() as u8, &encrypted);
+
+    Ok(groups
+        .iter()
+        .zip(group_secrets)
+        .map(|((member_threshold, member_count), (group_index, group_secret))| {
+            split_secret(rng, *member_threshold, *member_count, &group_secret)
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Share {
+                        identifier,
+                        iteration_exponent: ITERATION_EXPONENT,
+                        group_index,
+                        group_threshold: threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold: *member_threshold,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Returns the master secret of the given SLIP-0039 mnemonic shares, decrypted with the passphrase.
+/// The shares must include a threshold of shares of a threshold of groups. As in SLIP-0039,
+/// a wrong passphrase is not detected, and yields a different master secret.
+pub fn combine_slip39(shares: &[&str], passphrase: &str) -> Result<Vec<u8>, MnemonicError> {
+    let shares = shares
+        .iter()
+        .map(|share| Share::from_mnemonic(share))
+        .collect::<Result<Vec<Share>, MnemonicError>>()?;
+    let first = match shares.first() {
+        Some(first) => first,
+        None => return Err(MnemonicError::InvalidShares("no shares".into())),
+    };
+
+    if shares.iter().any(|share| {
+        share.identifier != first.identifier
+            || share.iteration_exponent != first.iteration_exponent
+            || share.group_threshold != first.group_threshold
+            || share.group_count != first.group_count
+            || share.value.len() != first.value.len()
+    }) {
+        return Err(MnemonicError::InvalidShares(
+            "shares are not of the same master secret".into(),
+        ));
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    shares
+        .iter()
+        .for_each(|share| groups.entry(share.group_index).or_default().push(share));
+    if groups.len() < first.group_threshold as usize {
+        return Err(MnemonicError::InvalidShares(format!(
+            "{} of {} required groups",
+            groups.len(),
+            first.group_threshold
+        )));
+    }
+
+    let group_secrets = groups
+        .iter()
+        .map(|(group_index, members)| {
+            let member_threshold = members[0].member_threshold;
+            let mut values: BTreeMap<u8, Vec<u8>> = BTreeMap::new();
+            for member in members {
+                if member.member_threshold != member_threshold {
+                    return Err(MnemonicError::InvalidShares(format!(
+                        "inconsistent member thresholds in group {}",
+                        group_index
+                    )));
+                }
+                if values.insert(member.member_index, member.value.clone()).is_some() {
+                    return Err(MnemonicError::InvalidShares(format!(
+                        "duplicate member index {} in group {}",
+                        member.member_index, group_index
+                    )));
+                }
+            }
+            if values.len() < member_threshold as usize {
+                return Err(MnemonicError::InvalidShares(format!(
+                    "{} of {} required shares of group {}",
+                    values.len(),
+                    member_threshold,
+                    group_index
+                )));
+            }
+            let values: Vec<(u8, Vec<u8>)> = values.into_iter().collect();
+            Ok((*group_index, recover_secret(member_threshold, &values)?))
+        })
+        .collect::<Result<Vec<(u8, Vec<u8>)>, MnemonicError>>()?;
+
+    let encrypted = recover_secret(first.group_threshold, &group_secrets)?;
+    decrypt(&encrypted, passphrase, first.iteration_exponent, first.identifier)
+}
+
+/// Returns the 10-bit words of the given bytes, padded with leading zero bits to a whole number of words.
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    let count = (bytes.len() * 8).div_ceil(RADIX_BITS);
+    let mut words = Vec::with_capacity(count);
+    let (mut accumulator, mut bits) = (0u32, count * RADIX_BITS - bytes.len() * 8);
+    for byte in bytes {
+        accumulator = accumulator << 8 | u32::from(*byte);
+        bits += 8;
+        while bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(accumulator >> bits & 0x3ff);
+        }
+        accumulator &= (1 << bits) - 1;
+    }
+    words
+}
+
+/// Returns the bytes of the given 10-bit words of a share value, an even number of bytes preceded by
+/// at most 8 zero bits of padding, or `None` if the padding is invalid.
+fn from_words(words: &[u32]) -> Option<Vec<u8>> {
+    let padding = (words.len() * RADIX_BITS) % 16;
+    if padding > 8 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity((words.len() * RADIX_BITS - padding) / 8);
+    let (mut accumulator, mut bits) = (0u32, 0);
+    for (i, word) in words.iter().enumerate() {
+        accumulator = accumulator << RADIX_BITS | word;
+        bits += RADIX_BITS;
+        if i == 0 {
+            bits -= padding;
+            if accumulator >> bits != 0 {
+                return None;
+            }
+        }
+        while bits >= 8 {
+            bits -= 8;
+            bytes.push((accumulator >> bits) as u8);
+        }
+        accumulator &= (1 << bits) - 1;
+    }
+    Some(bytes)
+}
+
+/// Returns the RS1024 checksum polynomial of the customization string followed by the given words.
+fn checksum(words: &[u32]) -> u32 {
+    CUSTOMIZATION
+        .iter()
+        .map(|byte| u32::from(*byte))
+        .chain(words.iter().cloned())
+        .fold(1u32, |checksum, word| {
+            let top = checksum >> 20;
+            (0..10).fold((checksum & 0xfffff) << RADIX_BITS ^ word, |checksum, i| {
+                match (top >> i) & 1 {
+                    1 => checksum ^ CHECKSUM_GENERATOR[i],
+                    _ => checksum,
+                }
+            })
+        })
+}
+
+/// Returns the 3 checksum words of the given words.
+fn create_checksum(words: &[u32]) -> Vec<u32> {
+    let mut data = words.to_vec();
+    data.extend([0; CHECKSUM_WORDS]);
+    let checksum = checksum(&data) ^ 1;
+    (0..CHECKSUM_WORDS)
+        .rev()
+        .map(|i| (checksum >> (RADIX_BITS * i)) & 0x3ff)
+        .collect()
+}
+
+/// Returns the master secret encrypted with the passphrase by a 4-round Feistel network of PBKDF2.
+fn encrypt(secret: &[u8], passphrase: &str, iteration_exponent: u8, identifier: u16) -> Result<Vec<u8>, MnemonicError> {
+    feistel(secret, passphrase, iteration_exponent, identifier, &[0, 1, 2, 3])
+}
+
+/// Returns the master secret of the given encrypted master secret, decrypted with the passphrase.
+fn decrypt(
+    encrypted: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+) -> Result<Vec<u8>, MnemonicError> {
+    feistel(encrypted, passphrase, iteration_exponent, identifier, &[3, 2, 1, 0])
+}
+
+/// Returns the output of the Feistel network of the given rounds,
+/// whose round function is PBKDF2-HMAC-SHA256 of the round and passphrase.
+fn feistel(
+    input: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    rounds: &[u8],
+) -> Result<Vec<u8>, MnemonicError> {
+    // The passphrase must consist of printable ASCII characters.
+    if !passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        return Err(MnemonicError::InvalidPhrase("passphrase is not printable ASCII".into()));
+    }
+
+    let iterations = 1u32
+        .checked_shl(u32::from(iteration_exponent))
+        .and_then(|factor| BASE_ITERATION_COUNT.checked_mul(factor))
+        .ok_or_else(|| MnemonicError::InvalidShares(format!("invalid iteration exponent {}", iteration_exponent)))?
+        / u32::from(ROUND_COUNT);
+    let half = input.len() / 2;
+    let (mut left, mut right) = (input[..half].to_vec(), input[half..].to_vec());
+    for round in rounds {
+        let mut password = vec![*round];
+        password.extend(passphrase.as_bytes());
+        let mut salt = CUSTOMIZATION.to_vec();
+        salt.extend(&identifier.to_be_bytes());
+        salt.extend(&right);
+
+        let mut output = vec![0u8; half];
+        pbkdf2(&mut Hmac::new(Sha256::new(), &password), &salt, iterations, &mut output);
+        let next = left.iter().zip(&output).map(|(left, output)| left ^ output).collect();
+        left = right;
+        right = next;
+    }
+
+    Ok([right, left].concat())
+}
+
+/// Returns the digest of a shared secret, the first 4 bytes of its HMAC-SHA256 keyed by the random part.
+fn digest(random_part: &[u8], secret: &[u8]) -> Vec<u8> {
+    let mut hmac = Hmac::new(Sha256::new(), random_part);
+    hmac.input(secret);
+    hmac.result().code()[..DIGEST_LENGTH].to_vec()
+}
+
+/// Returns `count` shares of the secret, any `threshold` of which recover it. The shares of a
+/// threshold above 1 also carry a digest of the secret, at the digest index, to verify its recovery.
+fn split_secret<R: Rng>(rng: &mut R, threshold: u8, count: u8, secret: &[u8]) -> Vec<(u8, Vec<u8>)> {
+    if threshold == 1 {
+        return (0..count).map(|index| (index, secret.to_vec())).collect();
+    }
+
+    let random_count = threshold - 2;
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..random_count)
+        .map(|index| (index, (0..secret.len()).map(|_| rng.gen()).collect()))
+        .collect();
+
+    let random_part: Vec<u8> = (0..secret.len() - DIGEST_LENGTH).map(|_| rng.gen()).collect();
+    let mut digest_share = digest(&random_part, secret);
+    digest_share.extend(random_part);
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest_share));
+    base_shares.push((SECRET_INDEX, secret.to_vec()));
+    shares.extend((random_count..count).map(|index| (index, interpolate(&base_shares, index))));
+    shares
+}
+
+/// Returns the secret of the given shares of a threshold, verifying the digest of the secret.
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, MnemonicError> {
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if digest(&digest_share[DIGEST_LENGTH..], &secret) != digest_share[..DIGEST_LENGTH] {
+        return Err(MnemonicError::InvalidShares(
+            "invalid digest of the shared secret".into(),
+        ));
+    }
+    Ok(secret)
+}
+
+/// Returns the value at x of the polynomials over GF(256) through the given shares of distinct indices,
+/// evaluated bytewise by Lagrange interpolation.
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Vec<u8> {
+    if let Some((_, value)) = shares.iter().find(|(index, _)| *index == x) {
+        return value.clone();
+    }
+
+    // The exponents and logarithms of GF(256) with the Rijndael polynomial and the generator 3
+    let mut exp = [0u8; 255];
+    let mut log = [0usize; 256];
+    let mut power = 1u16;
+    for (i, exp) in exp.iter_mut().enumerate() {
+        *exp = power as u8;
+        log[power as usize] = i;
+        power ^= power << 1;
+        if power & 0x100 != 0 {
+            power ^= 0x11b;
+        }
+    }
+
+    let log_product: usize = shares.iter().map(|(index, _)| log[(index ^ x) as usize]).sum();
+    let mut result = vec![0u8; shares[0].1.len()];
+    for (index, value) in shares {
+        let log_denominator: usize = shares
+            .iter()
+            .filter(|(other, _)| other != index)
+            .map(|(other, _)| log[(index ^ other) as usize])
+            .sum();
+        let log_basis = (log_product + 255 * shares.len() - log[(index ^ x) as usize] - log_denominator) % 255;
+        result.iter_mut().zip(value).for_each(|(result, byte)| {
+            if *byte != 0 {
+                *result ^= exp[(log[*byte as usize] + log_basis) % 255];
+            }
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand_core::SeedableRng;
+
+    /// The passphrase of the SLIP-0039 test vectors
+    const PASSPHRASE: &str = "TREZOR";
+
+    #[test]
+    fn test_words() {
+        [vec![0u8; 16], vec![0xff; 16], (0..32).collect()]
+            .iter()
+            .for_each(|bytes| {
+                let words = to_words(bytes);
+                assert!(words.iter().all(|word| *word < 1024));
+                assert_eq!(Some(bytes.clone()), from_words(&words));
+            });
+
+        // The padding bits must be zero.
+        let mut words = to_words(&[0u8; 16]);
+        words[0] = 0x200;
+        assert_eq!(None, from_words(&words));
+    }
+
+    #[test]
+    fn test_combine_slip39() {
+        // SLIP-0039 test vector 1, a mnemonic without sharing (128 bits)
+        let share = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        assert_eq!(
+            "bb54aac4b89dc868ba37d9cc21b2cece",
+            hex::encode(combine_slip39(&[share], PASSPHRASE).unwrap())
+        );
+
+        // SLIP-0039 test vector 4, basic sharing 2-of-3 (128 bits)
+        let shares = [
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ];
+        assert_eq!(
+            "b43ceb7e57a0ea8766221624d01b0864",
+            hex::encode(combine_slip39(&shares, PASSPHRASE).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_combine_slip39_invalid() {
+        // SLIP-0039 test vector 2, a mnemonic with an invalid checksum (128 bits)
+        let share = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney";
+        assert!(combine_slip39(&[share], PASSPHRASE).is_err());
+
+        // SLIP-0039 test vector 5, basic sharing 2-of-3 with an insufficient number of shares (128 bits)
+        let share = "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed";
+        assert!(combine_slip39(&[share], PASSPHRASE).is_err());
+
+        // The same share twice is not two shares.
+        assert!(combine_slip39(&[share, share], PASSPHRASE).is_err());
+
+        assert!(combine_slip39(&[], PASSPHRASE).is_err());
+        assert!(combine_slip39(&["wagyu"], PASSPHRASE).is_err());
+
+        // A share whose iteration exponent would overflow, or cost billions of iterations, is rejected.
+        [MAX_ITERATION_EXPONENT + 1, 20, 31]
+            .iter()
+            .for_each(|iteration_exponent| {
+                let share = Share {
+                    identifier: 0,
+                    iteration_exponent: *iteration_exponent,
+                    group_index: 0,
+                    group_threshold: 1,
+                    group_count: 1,
+                    member_index: 0,
+                    member_threshold: 1,
+                    value: vec![0u8; 16],
+                }
+                .to_mnemonic();
+                assert!(matches!(
+                    combine_slip39(&[share.as_str()], ""),
+                    Err(MnemonicError::InvalidShares(_))
+                ));
+            });
+        assert!(feistel(&[0u8; 16], "", 31, 0, &[0, 1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_split_slip39() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let seed = hex::decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+
+        let groups = split_slip39(rng, &seed, &[(1, 1), (2, 3), (3, 5)], 2, PASSPHRASE).unwrap();
+        assert_eq!(vec![1, 3, 5], groups.iter().map(Vec::len).collect::<Vec<_>>());
+        groups
+            .iter()
+            .flatten()
+            .for_each(|share| assert_eq!(20, share.split(' ').count()));
+
+        let recovered = combine_slip39(&[&groups[0][0], &groups[1][2], &groups[1][0]], PASSPHRASE).unwrap();
+        assert_eq!(seed, recovered);
+        let recovered = combine_slip39(
+            &[
+                &groups[2][4],
+                &groups[1][1],
+                &groups[2][0],
+                &groups[1][2],
+                &groups[2][3],
+            ],
+            PASSPHRASE,
+        )
+        .unwrap();
+        assert_eq!(seed, recovered);
+
+        // A threshold of shares of fewer than a threshold of groups does not recover the secret.
+        assert!(combine_slip39(&[&groups[1][0], &groups[1][1]], PASSPHRASE).is_err());
+        assert!(combine_slip39(&[&groups[0][0], &groups[1][0]], PASSPHRASE).is_err());
+
+        // A wrong passphrase yields a different master secret.
+        let recovered = combine_slip39(&[&groups[0][0], &groups[1][2], &groups[1][0]], "").unwrap();
+        assert_ne!(seed, recovered);
+
+        // A 256-bit master secret
+        let seed: Vec<u8> = (0..32).collect();
+        let groups = split_slip39(rng, &seed, &[(2, 2)], 1, "").unwrap();
+        groups[0]
+            .iter()
+            .for_each(|share| assert_eq!(33, share.split(' ').count()));
+        assert_eq!(seed, combine_slip39(&[&groups[0][1], &groups[0][0]], "").unwrap());
+    }
+
+    #[test]
+    fn test_split_slip39_invalid() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let seed = [0u8; 16];
+
+        assert!(split_slip39(rng, &seed[..15], &[(1, 1)], 1, "").is_err());
+        assert!(split_slip39(rng, &[0u8; 17], &[(1, 1)], 1, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(1, 1)], 2, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(1, 1)], 0, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(3, 2)], 1, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(1, 2)], 1, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(2, 17)], 1, "").is_err());
+        assert!(split_slip39(rng, &seed, &[(1, 1)], 1, "é").is_err());
+    }
+}
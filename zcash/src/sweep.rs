@@ -0,0 +1,142 @@
+use crate::address::ZcashAddress;
+use crate::amount::ZcashAmount;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use wagyu_model::{AddressError, PrivateKey, PrivateKeyError};
+
+/// Represents an unspent transparent transaction output
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZcashUtxo {
+    /// The id of the transaction containing the output
+    pub transaction_id: [u8; 32],
+    /// The index of the output in the transaction
+    pub index: u32,
+    /// The value of the output
+    pub amount: ZcashAmount,
+}
+
+/// The interface for a source of unspent transparent outputs, such as a full node or block explorer.
+pub trait UtxoProvider {
+    /// Returns the unspent outputs of the given address.
+    fn get_utxos<N: ZcashNetwork>(&self, address: &ZcashAddress<N>) -> Result<Vec<ZcashUtxo>, AddressError>;
+}
+
+/// Represents an unsigned transaction spending every unspent output of a private key to one address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZcashSweepTransaction<N: ZcashNetwork> {
+    /// The unspent outputs being spent
+    pub inputs: Vec<ZcashUtxo>,
+    /// The address receiving the swept funds
+    pub destination: ZcashAddress<N>,
+    /// The value sent to the destination, which is the sum of the inputs less the fee
+    pub amount: ZcashAmount,
+    /// The transaction fee
+    pub fee: ZcashAmount,
+}
+
+impl<N: ZcashNetwork> ZcashPrivateKey<N> {
+    /// Returns an unsigned transaction sweeping the unspent outputs of the P2PKH address
+    /// of the private key, as reported by the given provider, to the destination address.
+    pub fn sweep<P: UtxoProvider>(
+        &self,
+        provider: &P,
+        destination: &ZcashAddress<N>,
+        fee: ZcashAmount,
+    ) -> Result<ZcashSweepTransaction<N>, AddressError> {
+        let address = match self {
+            ZcashPrivateKey::<N>::P2PKH(_) => self.to_address(&ZcashFormat::P2PKH)?,
+            _ => return Err(PrivateKeyError::UnsupportedFormat.into()),
+        };
+
+        let inputs = provider.get_utxos(&address)?;
+        if inputs.is_empty() {
+            return Err(AddressError::Message(format!("no unspent outputs for {}", address)));
+        }
+
+        let total = inputs.iter().try_fold(ZcashAmount(0), |total, utxo| {
+            total
+                .add(utxo.amount.clone())
+                .map_err(|error| AddressError::Message(error.to_string()))
+        })?;
+        if total <= fee {
+            return Err(AddressError::Message(format!(
+                "insufficient funds to sweep {} with a fee of {}",
+                total, fee
+            )));
+        }
+
+        Ok(ZcashSweepTransaction {
+            inputs,
+            destination: destination.clone(),
+            amount: total
+                .sub(fee.clone())
+                .map_err(|error| AddressError::Message(error.to_string()))?,
+            fee,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    use std::str::FromStr;
+
+    struct MockProvider(Vec<ZcashUtxo>);
+
+    impl UtxoProvider for MockProvider {
+        fn get_utxos<N: ZcashNetwork>(&self, _: &ZcashAddress<N>) -> Result<Vec<ZcashUtxo>, AddressError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        type N = Mainnet;
+
+        let private_key =
+            ZcashPrivateKey::<N>::from_str("L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        let destination = ZcashAddress::<N>::from_str("t1Rxy8Qw6eXxSRFLwS3S1D8T436eR4zQTKp").unwrap();
+        let utxos = vec![
+            ZcashUtxo {
+                transaction_id: [1u8; 32],
+                index: 0,
+                amount: ZcashAmount(50_000),
+            },
+            ZcashUtxo {
+                transaction_id: [2u8; 32],
+                index: 3,
+                amount: ZcashAmount(70_000),
+            },
+        ];
+
+        let transaction = private_key
+            .sweep(&MockProvider(utxos.clone()), &destination, ZcashAmount(10_000))
+            .unwrap();
+        assert_eq!(utxos, transaction.inputs);
+        assert_eq!(destination, transaction.destination);
+        assert_eq!(ZcashAmount(110_000), transaction.amount);
+        assert_eq!(ZcashAmount(10_000), transaction.fee);
+
+        // The fee exceeds the swept value
+        assert!(private_key
+            .sweep(&MockProvider(utxos), &destination, ZcashAmount(120_000))
+            .is_err());
+
+        // There is nothing to sweep
+        assert!(private_key
+            .sweep(&MockProvider(vec![]), &destination, ZcashAmount(10_000))
+            .is_err());
+
+        // Shielded keys have no transparent outputs
+        let sapling = ZcashPrivateKey::<N>::from_str(
+            "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx",
+        )
+        .unwrap();
+        assert!(sapling
+            .sweep(&MockProvider(vec![]), &destination, ZcashAmount(0))
+            .is_err());
+    }
+}
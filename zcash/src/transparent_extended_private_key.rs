@@ -0,0 +1,319 @@
+use crate::address::ZcashAddress;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::{P2PKHSpendingKey, ZcashPrivateKey};
+use crate::public_key::ZcashPublicKey;
+use crate::signature::SECP256K1;
+use wagyu_model::{
+    crypto::{checksum, hash160},
+    AddressError, ChildIndex, ExtendedPrivateKeyError, PrivateKey,
+};
+
+use base58::{FromBase58, ToBase58};
+use crypto::{hmac::Hmac, mac::Mac, sha2::Sha512};
+use secp256k1::{PublicKey, SecretKey};
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+/// Represents a Zcash BIP32 extended private key for transparent addresses
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ZcashTransparentExtendedPrivateKey<N: ZcashNetwork> {
+    /// The depth of key derivation, e.g. 0x00 for master nodes, 0x01 for level-1 derived keys, ...
+    pub(super) depth: u8,
+    /// The first 32 bits of the key identifier (hash160(ECDSA_public_key))
+    pub(super) parent_fingerprint: [u8; 4],
+    /// The child index of the key (0 for master key)
+    pub(super) child_index: ChildIndex,
+    /// The chain code for this extended private key
+    pub(super) chain_code: [u8; 32],
+    /// The ECDSA private key
+    pub(super) secret_key: SecretKey,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> ZcashTransparentExtendedPrivateKey<N> {
+    /// Returns a new Zcash transparent master extended private key from a given seed.
+    pub fn new_master(seed: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        let mut mac = Hmac::new(Sha512::new(), b"Bitcoin seed");
+        mac.input(seed);
+        let hmac = mac.result();
+        let hmac = hmac.code();
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&hmac[32..]);
+
+        Ok(Self {
+            depth: 0,
+            parent_fingerprint: [0u8; 4],
+            child_index: ChildIndex::Normal(0),
+            chain_code,
+            secret_key: SecretKey::from_slice(&hmac[0..32])?,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the extended private key of the given child index path, relative to this key.
+    pub fn derive(&self, path: &[ChildIndex]) -> Result<Self, ExtendedPrivateKeyError> {
+        let mut extended_private_key = self.clone();
+
+        for index in path {
+            if extended_private_key.depth == 255 {
+                return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(
+                    extended_private_key.depth,
+                ));
+            }
+
+            let public_key = &PublicKey::from_secret_key(&SECP256K1, &extended_private_key.secret_key).serialize()[..];
+            let parent_fingerprint = extended_private_key.fingerprint();
+
+            let mut mac = Hmac::new(Sha512::new(), &extended_private_key.chain_code);
+            match index {
+                // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
+                ChildIndex::Normal(_) => mac.input(public_key),
+                // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
+                // (Note: The 0x00 pads the private key to make it 33 bytes long.)
+                ChildIndex::Hardened(_) => {
+                    mac.input(&[0u8]);
+                    mac.input(&extended_private_key.secret_key[..]);
+                }
+            }
+            // Append the child index in big-endian format
+            mac.input(&u32::from(*index).to_be_bytes());
+            let hmac = mac.result();
+            let hmac = hmac.code();
+
+            let mut secret_key = SecretKey::from_slice(&hmac[0..32])?;
+            secret_key.add_assign(&extended_private_key.secret_key[..])?;
+
+            let mut chain_code = [0u8; 32];
+            chain_code.copy_from_slice(&hmac[32..]);
+
+            extended_private_key = Self {
+                depth: extended_private_key.depth + 1,
+                parent_fingerprint,
+                child_index: *index,
+                chain_code,
+                secret_key,
+                _network: PhantomData,
+            };
+        }
+
+        Ok(extended_private_key)
+    }
+
+    /// Returns the fingerprint of the extended private key, the first 4 bytes of the hash160 of its
+    /// compressed public key, which its children store as their parent fingerprint.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let public_key = PublicKey::from_secret_key(&SECP256K1, &self.secret_key).serialize();
+        let mut fingerprint = [0u8; 4];
+        fingerprint.copy_from_slice(&hash160(&public_key)[0..4]);
+        fingerprint
+    }
+
+    /// Returns the BIP32 serialization of the corresponding extended public key ("xpub" or "tpub").
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    pub fn to_xpub(&self) -> String {
+        let public_key = PublicKey::from_secret_key(&SECP256K1, &self.secret_key).serialize();
+        self.serialize(&N::to_transparent_extended_public_key_version_bytes(), &public_key)
+    }
+
+    /// Returns the base58check encoding of the version bytes, depth, parent fingerprint,
+    /// child index, chain code, and 33-byte key of the extended key.
+    fn serialize(&self, version: &[u8], key: &[u8; 33]) -> String {
+        let mut result = [0u8; 82];
+        result[0..4].copy_from_slice(version);
+        result[4] = self.depth;
+        result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
+        result[9..13].copy_from_slice(&u32::from(self.child_index).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code[..]);
+        result[45..78].copy_from_slice(&key[..]);
+
+        let checksum = &checksum(&result[0..78])[0..4];
+        result[78..82].copy_from_slice(checksum);
+
+        result.to_base58()
+    }
+
+    /// Returns the compressed P2PKH private key of the corresponding extended private key.
+    pub fn to_private_key(&self) -> ZcashPrivateKey<N> {
+        ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::new(self.secret_key, true))
+    }
+
+    /// Returns the P2PKH public key of the corresponding extended private key.
+    pub fn to_public_key(&self) -> ZcashPublicKey<N> {
+        self.to_private_key().to_public_key()
+    }
+
+    /// Returns the P2PKH address of the corresponding extended private key.
+    pub fn to_address(&self) -> Result<ZcashAddress<N>, AddressError> {
+        self.to_private_key().to_address(&ZcashFormat::P2PKH)
+    }
+}
+
+impl<N: ZcashNetwork> FromStr for ZcashTransparentExtendedPrivateKey<N> {
+    type Err = ExtendedPrivateKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 82 {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(data.len()));
+        }
+
+        // Check that the version bytes correspond with the correct network.
+        let _ = N::from_transparent_extended_private_key_version_bytes(&data[0..4])?;
+
+        let expected = &data[78..82];
+        let checksum = &checksum(&data[0..78])[0..4];
+        if *expected != *checksum {
+            let expected = expected.to_base58();
+            let found = checksum.to_base58();
+            return Err(ExtendedPrivateKeyError::InvalidChecksum(expected, found));
+        }
+
+        if data[45] != 0 {
+            return Err(ExtendedPrivateKeyError::Message(format!(
+                "invalid private key padding: {:#04x}",
+                data[45]
+            )));
+        }
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&data[5..9]);
+
+        let mut child_index = [0u8; 4];
+        child_index.copy_from_slice(&data[9..13]);
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+
+        Ok(Self {
+            depth: data[4],
+            parent_fingerprint,
+            child_index: ChildIndex::from(u32::from_be_bytes(child_index)),
+            chain_code,
+            secret_key: SecretKey::from_slice(&data[46..78])?,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: ZcashNetwork> Display for ZcashTransparentExtendedPrivateKey<N> {
+    /// BIP32 serialization format
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut key = [0u8; 33];
+        key[1..].copy_from_slice(&self.secret_key[..]);
+        f.write_str(&self.serialize(&N::to_transparent_extended_private_key_version_bytes(), &key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_derive<N: ZcashNetwork>(expected_address: &str, seed: &str, path: &[ChildIndex]) {
+        let master = ZcashTransparentExtendedPrivateKey::<N>::new_master(&hex::decode(seed).unwrap()).unwrap();
+        let extended_private_key = master.derive(path).unwrap();
+        assert_eq!(path.len() as u8, extended_private_key.depth);
+        assert_eq!(expected_address, extended_private_key.to_address().unwrap().to_string());
+
+        // Deriving the path one index at a time yields the same key.
+        let stepwise = path.iter().fold(master, |key, index| key.derive(&[*index]).unwrap());
+        assert_eq!(extended_private_key, stepwise);
+    }
+
+    /// Seed of "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    const SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+    #[test]
+    fn derive_mainnet() {
+        let path = [
+            ChildIndex::Hardened(44),
+            ChildIndex::Hardened(133),
+            ChildIndex::Hardened(0),
+            ChildIndex::Normal(0),
+            ChildIndex::Normal(0),
+        ];
+        test_derive::<Mainnet>("t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F", SEED, &path);
+    }
+
+    #[test]
+    fn derive_testnet() {
+        let path = [
+            ChildIndex::Hardened(44),
+            ChildIndex::Hardened(1),
+            ChildIndex::Hardened(0),
+            ChildIndex::Normal(0),
+            ChildIndex::Normal(0),
+        ];
+        test_derive::<Testnet>("tmF1xjfhsSzhy55dmhorzTnKjtHhZmPKzts", SEED, &path);
+    }
+
+    #[test]
+    fn fingerprint() {
+        // BIP32 test vector 1
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ZcashTransparentExtendedPrivateKey::<Mainnet>::new_master(&seed).unwrap();
+        assert_eq!("3442193e", hex::encode(master.fingerprint()));
+
+        let child = master.derive(&[ChildIndex::Hardened(0)]).unwrap();
+        assert_eq!("5c1bd648", hex::encode(child.fingerprint()));
+        assert_eq!(master.fingerprint(), child.parent_fingerprint);
+
+        let grandchild = child.derive(&[ChildIndex::Normal(1)]).unwrap();
+        assert_eq!("bef5a2f9", hex::encode(grandchild.fingerprint()));
+        assert_eq!(child.fingerprint(), grandchild.parent_fingerprint);
+    }
+
+    #[test]
+    fn to_string() {
+        // BIP32 test vector 1
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let vectors = [
+            (
+                vec![],
+                "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+                "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+            ),
+            (
+                vec![ChildIndex::Hardened(0)],
+                "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+                "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            ),
+            (
+                vec![ChildIndex::Hardened(0), ChildIndex::Normal(1)],
+                "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+                "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+            ),
+        ];
+
+        let master = ZcashTransparentExtendedPrivateKey::<Mainnet>::new_master(&seed).unwrap();
+        vectors.iter().for_each(|(path, expected_xprv, expected_xpub)| {
+            let extended_private_key = master.derive(path).unwrap();
+            assert_eq!(*expected_xprv, extended_private_key.to_string());
+            assert_eq!(*expected_xpub, extended_private_key.to_xpub());
+            assert_eq!(
+                extended_private_key,
+                ZcashTransparentExtendedPrivateKey::<Mainnet>::from_str(expected_xprv).unwrap()
+            );
+        });
+
+        // Testnet
+        let master = ZcashTransparentExtendedPrivateKey::<Testnet>::new_master(&seed).unwrap();
+        let tprv = master.to_string();
+        assert!(tprv.starts_with("tprv"));
+        assert!(master.to_xpub().starts_with("tpub"));
+        assert_eq!(
+            master,
+            ZcashTransparentExtendedPrivateKey::<Testnet>::from_str(&tprv).unwrap()
+        );
+        assert!(ZcashTransparentExtendedPrivateKey::<Mainnet>::from_str(&tprv).is_err());
+        assert!(ZcashTransparentExtendedPrivateKey::<Testnet>::from_str(vectors[0].1).is_err());
+        assert!(ZcashTransparentExtendedPrivateKey::<Mainnet>::from_str(vectors[0].2).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::address::ZcashAddress;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use wagyu_model::{AddressError, PrivateKey};
+
+use base58::FromBase58;
+use rand::Rng;
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+impl<N: ZcashNetwork> ZcashPrivateKey<N> {
+    /// Returns the first randomly-generated compressed P2PKH private key, and its address,
+    /// whose address begins with the given prefix, trying at most `max_attempts` keys.
+    pub fn vanity_search<R: Rng>(
+        rng: &mut R,
+        prefix: &str,
+        max_attempts: u64,
+    ) -> Result<(Self, ZcashAddress<N>), AddressError> {
+        // Every character of an address is base58, so a prefix with any other character never matches.
+        if !prefix.is_empty() && prefix.from_base58().is_err() {
+            return Err(AddressError::Message(format!("invalid base58 prefix: {}", prefix)));
+        }
+
+        for _ in 0..max_attempts {
+            let private_key = Self::new_p2pkh(rng)?;
+            let address = private_key.to_address(&ZcashFormat::P2PKH)?;
+            if address.to_string().starts_with(prefix) {
+                return Ok((private_key, address));
+            }
+        }
+
+        Err(AddressError::Message(format!(
+            "no address with prefix {} found in {} attempts",
+            prefix, max_attempts
+        )))
+    }
+
+    /// Returns the result of `vanity_search` with a ChaCha20 random number generator of the given seed,
+    /// so that the same seed and prefix always find the same private key, across runs and releases.
+    pub fn vanity_search_seeded(
+        seed: u64,
+        prefix: &str,
+        max_attempts: u64,
+    ) -> Result<(Self, ZcashAddress<N>), AddressError> {
+        Self::vanity_search(&mut ChaCha20Rng::seed_from_u64(seed), prefix, max_attempts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    #[test]
+    fn vanity_search_seeded() {
+        type N = Mainnet;
+
+        let (private_key, address) = ZcashPrivateKey::<N>::vanity_search_seeded(7, "t1", 1).unwrap();
+        assert!(address.to_string().starts_with("t1"));
+        assert_eq!(address, private_key.to_address(&ZcashFormat::P2PKH).unwrap());
+
+        // The same seed finds the same key, and a different seed finds a different key.
+        let (repeated, _) = ZcashPrivateKey::<N>::vanity_search_seeded(7, "t1", 1).unwrap();
+        assert_eq!(private_key, repeated);
+        let (other, _) = ZcashPrivateKey::<N>::vanity_search_seeded(8, "t1", 1).unwrap();
+        assert_ne!(private_key, other);
+
+        let (private_key, address) = ZcashPrivateKey::<N>::vanity_search_seeded(7, "t1J", 10_000).unwrap();
+        assert_eq!(
+            "Kz6gK9m32uegBzuid4NED1esR7MaDvhn7biVpDNySURyVP3evn7J",
+            private_key.to_string()
+        );
+        assert_eq!("t1JvYmTYbH5GDMhu2BthGoHHp77YM57jVDD", address.to_string());
+    }
+
+    #[test]
+    fn vanity_search_invalid() {
+        type N = Mainnet;
+
+        assert!(ZcashPrivateKey::<N>::vanity_search_seeded(7, "t10", 10).is_err());
+        assert!(ZcashPrivateKey::<N>::vanity_search_seeded(7, "tm", 10).is_err());
+    }
+}
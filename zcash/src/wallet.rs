@@ -0,0 +1,313 @@
+use crate::address::ZcashAddress;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use crate::transparent_extended_private_key::ZcashTransparentExtendedPrivateKey;
+use wagyu_model::{
+    crypto::{checksum, ct_eq_str},
+    ChildIndex, ExtendedPrivateKeyError, NetworkError, PrivateKey,
+};
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// The version of the wallet file format
+const WALLET_VERSION: u8 = 1;
+
+/// Represents a BIP44 chain of an account
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chain {
+    /// The external chain of receiving addresses
+    External,
+    /// The internal chain of change addresses
+    Internal,
+}
+
+impl From<Chain> for ChildIndex {
+    fn from(chain: Chain) -> Self {
+        match chain {
+            Chain::External => ChildIndex::Normal(0),
+            Chain::Internal => ChildIndex::Normal(1),
+        }
+    }
+}
+
+/// Represents a Zcash wallet of transparent private keys derived from a seed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZcashWallet<N: ZcashNetwork> {
+    /// The seed of the wallet
+    seed: Vec<u8>,
+    /// The private keys of the external chain, in derivation order
+    private_keys: Vec<ZcashPrivateKey<N>>,
+    /// The index of the next unused receiving address
+    index: u32,
+}
+
+/// The contents of a wallet file covered by its checksum
+#[derive(Serialize, Deserialize)]
+struct WalletData {
+    version: u8,
+    network: String,
+    seed: String,
+    private_keys: Vec<String>,
+    index: u32,
+}
+
+/// The serialized form of a wallet file
+#[derive(Serialize, Deserialize)]
+struct WalletFile {
+    wallet: WalletData,
+    checksum: String,
+}
+
+impl<N: ZcashNetwork> ZcashWallet<N> {
+    /// Returns a new wallet of the given seed with its first `count` private keys,
+    /// derived with the BIP44 path m/44'/{133', 1'}/0'/0/{index}.
+    pub fn new(seed: &[u8], count: u32) -> Result<Self, ExtendedPrivateKeyError> {
+        let chain = Self::chain(seed, Chain::External)?;
+        let private_keys = (0..count)
+            .map(|index| Ok(chain.derive(&[ChildIndex::normal(index)?])?.to_private_key()))
+            .collect::<Result<_, ExtendedPrivateKeyError>>()?;
+
+        Ok(Self {
+            seed: seed.to_vec(),
+            private_keys,
+            index: 0,
+        })
+    }
+
+    /// Returns the seed of the wallet.
+    pub fn seed(&self) -> &[u8] {
+        &self.seed
+    }
+
+    /// Returns the private keys of the wallet, in derivation order.
+    pub fn private_keys(&self) -> &[ZcashPrivateKey<N>] {
+        &self.private_keys
+    }
+
+    /// Returns the index of the next unused receiving address.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the next unused P2PKH receiving address of the external chain and advances the index,
+    /// deriving and storing its private key if the wallet does not already hold it.
+    pub fn next_address(&mut self) -> Result<ZcashAddress<N>, ExtendedPrivateKeyError> {
+        let index = self.index as usize;
+        if index == self.private_keys.len() {
+            let child = ChildIndex::normal(self.index)?;
+            let private_key = Self::chain(&self.seed, Chain::External)?
+                .derive(&[child])?
+                .to_private_key();
+            self.private_keys.push(private_key);
+        }
+
+        let address = self.private_keys[index]
+            .to_address(&ZcashFormat::P2PKH)
+            .map_err(|error| ExtendedPrivateKeyError::Message(error.to_string()))?;
+        self.index += 1;
+        Ok(address)
+    }
+
+    /// Returns the P2PKH address at the given index of the receiving (external) or change (internal) chain,
+    /// derived with the BIP44 path m/44'/{133', 1'}/0'/{0, 1}/{index}. The wallet index is unchanged.
+    pub fn address_for(&self, chain: Chain, index: u32) -> Result<ZcashAddress<N>, ExtendedPrivateKeyError> {
+        Self::chain(&self.seed, chain)?
+            .derive(&[ChildIndex::normal(index)?])?
+            .to_address()
+            .map_err(|error| ExtendedPrivateKeyError::Message(error.to_string()))
+    }
+
+    /// Writes the wallet to the given writer as versioned, checksummed JSON.
+    pub fn save<W: Write>(&self, writer: W) -> Result<(), ExtendedPrivateKeyError> {
+        let wallet = WalletData {
+            version: WALLET_VERSION,
+            network: N::NAME.into(),
+            seed: hex::encode(&self.seed),
+            private_keys: self.private_keys.iter().map(|key| key.to_string()).collect(),
+            index: self.index,
+        };
+        let checksum = Self::checksum(&wallet)?;
+
+        serde_json::to_writer_pretty(writer, &WalletFile { wallet, checksum })
+            .map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))
+    }
+
+    /// Returns the wallet read from the given reader, verifying its version, checksum, network, and keys.
+    pub fn load<R: Read>(reader: R) -> Result<Self, ExtendedPrivateKeyError> {
+        let file: WalletFile = serde_json::from_reader(reader)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))?;
+
+        let wallet = file.wallet;
+        if wallet.version != WALLET_VERSION {
+            return Err(ExtendedPrivateKeyError::InvalidVersionBytes(vec![wallet.version]));
+        }
+
+        let expected = Self::checksum(&wallet)?;
+        if file.checksum != expected {
+            return Err(ExtendedPrivateKeyError::InvalidChecksum(expected, file.checksum));
+        }
+
+        if wallet.network != N::NAME {
+            return Err(NetworkError::InvalidNetwork(wallet.network).into());
+        }
+
+        let seed =
+            hex::decode(&wallet.seed).map_err(|error| ExtendedPrivateKeyError::Crate("hex", format!("{:?}", error)))?;
+        // The stored keys must be the ones derived from the stored seed.
+        let mut expected = Self::new(&seed, wallet.private_keys.len() as u32)?;
+        let matches = expected
+            .private_keys
+            .iter()
+            .zip(&wallet.private_keys)
+            .fold(true, |matches, (private_key, stored)| {
+                ct_eq_str(&private_key.to_string(), stored) & matches
+            });
+        if !matches {
+            return Err(ExtendedPrivateKeyError::Message(
+                "wallet private keys do not match its seed".into(),
+            ));
+        }
+
+        if wallet.index as usize > wallet.private_keys.len() {
+            return Err(ExtendedPrivateKeyError::Message(format!(
+                "invalid wallet address index: {}",
+                wallet.index
+            )));
+        }
+        expected.index = wallet.index;
+
+        Ok(expected)
+    }
+
+    /// Returns the extended private key of the given chain m/44'/{133', 1'}/0'/{0, 1}.
+    fn chain(seed: &[u8], chain: Chain) -> Result<ZcashTransparentExtendedPrivateKey<N>, ExtendedPrivateKeyError> {
+        ZcashTransparentExtendedPrivateKey::<N>::new_master(seed)?.derive(&[
+            ChildIndex::Hardened(44),
+            N::HD_COIN_TYPE,
+            ChildIndex::Hardened(0),
+            chain.into(),
+        ])
+    }
+
+    /// Returns the hex encoding of the first 4 bytes of the double SHA256 of the wallet data.
+    fn checksum(wallet: &WalletData) -> Result<String, ExtendedPrivateKeyError> {
+        let data = serde_json::to_vec(wallet)
+            .map_err(|error| ExtendedPrivateKeyError::Crate("serde_json", format!("{:?}", error)))?;
+        Ok(hex::encode(&checksum(&data)[0..4]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_round_trip<N: ZcashNetwork>(seed: &[u8], count: u32) {
+        let wallet = ZcashWallet::<N>::new(seed, count).unwrap();
+        assert_eq!(count as usize, wallet.private_keys().len());
+
+        let mut file = vec![];
+        wallet.save(&mut file).unwrap();
+        let loaded = ZcashWallet::<N>::load(&file[..]).unwrap();
+        assert_eq!(wallet, loaded);
+
+        // Saving is deterministic.
+        let mut resaved = vec![];
+        loaded.save(&mut resaved).unwrap();
+        assert_eq!(file, resaved);
+    }
+
+    /// Seed of "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    const SEED: &str = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+
+    #[test]
+    fn round_trip() {
+        let seed = hex::decode(SEED).unwrap();
+        test_round_trip::<Mainnet>(&seed, 3);
+        test_round_trip::<Testnet>(&seed, 3);
+        test_round_trip::<Mainnet>(&seed, 0);
+    }
+
+    #[test]
+    fn next_address() {
+        type N = Mainnet;
+
+        let expected_addresses = [
+            "t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F",
+            "t1aQ2b1XszNVo15BguYLbQGqETBL9QZA8Jq",
+            "t1gLHX58QpcZr2SfaMhc5XbxabE455KZhoA",
+        ];
+        let seed = hex::decode(SEED).unwrap();
+
+        let mut wallet = ZcashWallet::<N>::new(&seed, 0).unwrap();
+        expected_addresses
+            .iter()
+            .enumerate()
+            .for_each(|(index, expected_address)| {
+                assert_eq!(index as u32, wallet.index());
+                assert_eq!(*expected_address, wallet.next_address().unwrap().to_string());
+            });
+        assert_eq!(3, wallet.index());
+        assert_eq!(3, wallet.private_keys().len());
+
+        // Keys already held by the wallet are handed out before new ones are derived.
+        let mut wallet = ZcashWallet::<N>::new(&seed, 2).unwrap();
+        assert_eq!(expected_addresses[0], wallet.next_address().unwrap().to_string());
+        assert_eq!(2, wallet.private_keys().len());
+
+        // The index is persisted in the wallet file.
+        assert_eq!(expected_addresses[1], wallet.next_address().unwrap().to_string());
+        let mut file = vec![];
+        wallet.save(&mut file).unwrap();
+        let mut loaded = ZcashWallet::<N>::load(&file[..]).unwrap();
+        assert_eq!(wallet, loaded);
+        assert_eq!(expected_addresses[2], loaded.next_address().unwrap().to_string());
+    }
+
+    #[test]
+    fn address_for() {
+        type N = Mainnet;
+
+        let seed = hex::decode(SEED).unwrap();
+        let mut wallet = ZcashWallet::<N>::new(&seed, 0).unwrap();
+
+        (0..3).for_each(|index| {
+            let external = wallet.address_for(Chain::External, index).unwrap();
+            let internal = wallet.address_for(Chain::Internal, index).unwrap();
+            assert_ne!(external, internal);
+            assert_eq!(external, wallet.next_address().unwrap());
+        });
+        assert_eq!(
+            "t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F",
+            wallet.address_for(Chain::External, 0).unwrap().to_string()
+        );
+        assert_ne!(
+            wallet.address_for(Chain::Internal, 0).unwrap(),
+            wallet.address_for(Chain::Internal, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn load_invalid() {
+        let seed = hex::decode(SEED).unwrap();
+        let mut file = vec![];
+        ZcashWallet::<Mainnet>::new(&seed, 3).unwrap().save(&mut file).unwrap();
+        let file = String::from_utf8(file).unwrap();
+
+        // Invalid network
+        assert!(ZcashWallet::<Testnet>::load(file.as_bytes()).is_err());
+
+        // Invalid checksum
+        let tampered = file.replacen("5eb00bbd", "5eb00bbe", 1);
+        assert!(ZcashWallet::<Mainnet>::load(tampered.as_bytes()).is_err());
+
+        // Invalid version
+        let tampered = file.replacen("\"version\": 1", "\"version\": 2", 1);
+        assert!(ZcashWallet::<Mainnet>::load(tampered.as_bytes()).is_err());
+
+        // Invalid JSON
+        assert!(ZcashWallet::<Mainnet>::load(&file.as_bytes()[1..]).is_err());
+    }
+}